@@ -0,0 +1,76 @@
+#![allow(unexpected_cfgs)]
+// constant product pool used by the fundraiser tests to exercise contribute-with-swap
+// follows the swap interface expected by `process_contribute_instruction`:
+// data: [0, amount_in (u64), min_out (u64)]
+// accounts: [user, user_source, user_destination, pool_authority, pool_source, pool_destination, token_program]
+use pinocchio::{
+    address::declare_id,
+    cpi::{Seed, Signer},
+    entrypoint,
+    error::ProgramError,
+    AccountView, Address, ProgramResult,
+};
+use pinocchio_token::{instructions::Transfer, state::TokenAccount};
+
+entrypoint!(process_instruction);
+
+declare_id!("64gYuQi574h1evdHjdR1a5fAxnv88iEvpA4yqvrmHDe1");
+
+pub fn process_instruction(
+    program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    assert_eq!(program_id, &ID);
+
+    let [user, user_source, user_destination, pool_authority, pool_source, pool_destination, _token_program, ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let (discriminator, data) = instruction_data
+        .split_first()
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    if *discriminator != 0 || data.len() < 16 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let amount_in = u64::from_le_bytes(data[0..8].try_into().unwrap());
+    let min_out = u64::from_le_bytes(data[8..16].try_into().unwrap());
+
+    let (pool_address, pool_bump) = Address::find_program_address(&[b"pool"], &ID);
+    assert!(
+        pool_authority.address().eq(&pool_address),
+        "Pool authority does not match"
+    );
+
+    let reserve_in = TokenAccount::from_account_view(pool_source)?.amount() as u128;
+    let reserve_out = TokenAccount::from_account_view(pool_destination)?.amount() as u128;
+
+    // x * y = k, no fee
+    let amount_out = (reserve_out * amount_in as u128 / (reserve_in + amount_in as u128)) as u64;
+    assert!(amount_out >= min_out, "Slippage exceeded");
+
+    Transfer {
+        amount: amount_in,
+        authority: user,
+        from: user_source,
+        to: pool_source,
+    }
+    .invoke()?;
+
+    let bump = pool_bump.to_le_bytes();
+    let pool_seeds = [Seed::from(b"pool"), Seed::from(&bump)];
+
+    Transfer {
+        amount: amount_out,
+        authority: pool_authority,
+        from: pool_destination,
+        to: user_destination,
+    }
+    .invoke_signed(&[Signer::from(&pool_seeds)])?;
+
+    Ok(())
+}
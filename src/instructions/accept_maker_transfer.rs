@@ -0,0 +1,43 @@
+use pinocchio::{error::ProgramError, AccountView, Address, ProgramResult};
+
+use crate::state::Fundraiser;
+
+// pending maker takes control of the fundraiser, its address stays the same
+// the beneficiary moves along when it was the old maker
+// accounts: [new maker, fundraiser]
+pub fn process_accept_maker_transfer_instruction(accounts: &[AccountView]) -> ProgramResult {
+    let [new_maker, fundraiser, _extra @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    assert!(new_maker.is_signer(), "New maker should be signer");
+    assert!(
+        fundraiser.owned_by(&crate::ID),
+        "Invalid Fundraiser Account"
+    );
+
+    let mut fundraiser_data = fundraiser.try_borrow_mut().unwrap();
+    let fundraiser_mutable =
+        bytemuck::from_bytes_mut::<Fundraiser>(&mut fundraiser_data[..Fundraiser::LEN]);
+
+    assert!(
+        fundraiser_mutable.pending_maker != [0u8; 32],
+        "No maker transfer pending"
+    );
+    assert!(
+        new_maker
+            .address()
+            .eq(&Address::new_from_array(fundraiser_mutable.pending_maker)),
+        "Pending maker does not match"
+    );
+
+    // payouts that went to the old maker follow the new one, a separate beneficiary stays
+    if fundraiser_mutable.beneficiary == fundraiser_mutable.maker {
+        fundraiser_mutable.beneficiary = fundraiser_mutable.pending_maker;
+    }
+
+    fundraiser_mutable.maker = fundraiser_mutable.pending_maker;
+    fundraiser_mutable.pending_maker = [0u8; 32];
+
+    Ok(())
+}
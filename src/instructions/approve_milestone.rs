@@ -0,0 +1,50 @@
+use pinocchio::{error::ProgramError, AccountView, Address, ProgramResult};
+
+use crate::state::{Fundraiser, MILESTONE_APPROVED, MILESTONE_SUBMITTED};
+
+// approver signs off a submitted milestone - data: [milestone index]
+pub fn process_approve_milestone_instruction(
+    accounts: &[AccountView],
+    data: &[u8],
+) -> ProgramResult {
+    let [approver, fundraiser, _extra @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let index = *data.first().ok_or(ProgramError::InvalidInstructionData)? as usize;
+
+    assert!(approver.is_signer(), "Approver should be signer");
+    assert!(
+        fundraiser.owned_by(&crate::ID),
+        "Invalid Fundraiser Account"
+    );
+
+    let mut fundraiser_data = fundraiser.try_borrow_mut().unwrap();
+    let fundraiser_mutable =
+        bytemuck::from_bytes_mut::<Fundraiser>(&mut fundraiser_data[..Fundraiser::LEN]);
+
+    assert!(
+        !fundraiser_mutable.is_vote_approved(),
+        "Milestones are approved by vote"
+    );
+    assert!(
+        approver
+            .address()
+            .eq(&Address::new_from_array(fundraiser_mutable.approver)),
+        "Approver does not match"
+    );
+    assert!(
+        index < fundraiser_mutable.milestones_count[0] as usize,
+        "Invalid milestone"
+    );
+
+    let milestone = &mut fundraiser_mutable.milestones[index];
+    assert!(
+        milestone.status[0] == MILESTONE_SUBMITTED,
+        "Milestone not submitted"
+    );
+
+    milestone.status = [MILESTONE_APPROVED];
+
+    Ok(())
+}
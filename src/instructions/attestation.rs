@@ -0,0 +1,75 @@
+use pinocchio::{sysvars::instructions::Instructions, AccountView, Address};
+
+// Ed25519SigVerify111111111111111111111111111
+pub const ED25519_PROGRAM_ID: Address = Address::new_from_array([
+    3, 125, 70, 214, 124, 147, 251, 190, 18, 249, 66, 143, 131, 141, 64, 255, 5, 112, 116, 73, 39,
+    244, 138, 100, 252, 202, 112, 68, 128, 0, 0, 0,
+]);
+
+// attested message: contributor (32) | fundraiser (32) | expiry (i64)
+pub const ATTESTATION_LEN: usize = 72;
+
+// ed25519 program data: [signatures count, padding, offsets (14 bytes) per signature, ...]
+const ED25519_OFFSETS_START: usize = 2;
+const ED25519_OFFSETS_LEN: usize = 14;
+
+// an ed25519 verify instruction earlier in the transaction checked `attestor` signed
+// (contributor, fundraiser, expiry) and the expiry is still ahead
+pub fn verify_attestation(
+    instructions_sysvar: &AccountView,
+    attestor: &[u8; 32],
+    contributor: &Address,
+    fundraiser: &Address,
+    now: i64,
+) {
+    let instructions = Instructions::try_from(instructions_sysvar).expect("Invalid instructions");
+    let current_index = instructions.load_current_index() as usize;
+
+    let attested = (0..current_index).any(|index| {
+        let instruction = instructions.load_instruction_at(index).unwrap();
+
+        if !instruction.get_program_id().eq(&ED25519_PROGRAM_ID) {
+            return false;
+        }
+
+        let data = instruction.get_instruction_data();
+
+        // a single signature whose key and message live in that same instruction
+        if data.len() < ED25519_OFFSETS_START + ED25519_OFFSETS_LEN || data[0] != 1 {
+            return false;
+        }
+
+        let offsets = &data[ED25519_OFFSETS_START..ED25519_OFFSETS_START + ED25519_OFFSETS_LEN];
+        let read_u16 = |at: usize| u16::from_le_bytes([offsets[at], offsets[at + 1]]);
+
+        let [_signature_offset, signature_index, public_key_offset, public_key_index, message_offset, message_size, message_index] =
+            core::array::from_fn(|field| read_u16(field * 2));
+
+        if signature_index != u16::MAX
+            || public_key_index != u16::MAX
+            || message_index != u16::MAX
+            || message_size as usize != ATTESTATION_LEN
+        {
+            return false;
+        }
+
+        let (public_key_offset, message_offset) =
+            (public_key_offset as usize, message_offset as usize);
+
+        let (Some(public_key), Some(message)) = (
+            data.get(public_key_offset..public_key_offset + 32),
+            data.get(message_offset..message_offset + ATTESTATION_LEN),
+        ) else {
+            return false;
+        };
+
+        let expiry = i64::from_le_bytes(message[64..72].try_into().unwrap());
+
+        public_key == attestor.as_ref()
+            && &message[..32] == contributor.as_ref()
+            && &message[32..64] == fundraiser.as_ref()
+            && expiry > now
+    });
+
+    assert!(attested, "Contribution not attested");
+}
@@ -0,0 +1,127 @@
+use pinocchio::{
+    cpi::{Seed, Signer},
+    error::ProgramError,
+    sysvars::{clock::Clock, rent::Rent, Sysvar},
+    AccountView, Address, ProgramResult,
+};
+use pinocchio_system::instructions::CreateAccount;
+
+use crate::instructions::check_contributor;
+use crate::state::{Contributor, Fundraiser, Vote, MILESTONE_SUBMITTED};
+
+// contributor approves / rejects a submitted milestone, weighted by their amount
+// data: [milestone index, approve (1) / reject (0)]
+pub fn process_cast_vote_instruction(accounts: &[AccountView], data: &[u8]) -> ProgramResult {
+    let [contributor, fundraiser, contributor_state_account, vote, _system_program, _extra @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let [index, approve, ..] = data else {
+        return Err(ProgramError::InvalidInstructionData);
+    };
+    let index = *index as usize;
+    let approve = *approve == 1;
+
+    assert!(contributor.is_signer(), "Contributor must be signer");
+    assert!(
+        fundraiser.owned_by(&crate::ID),
+        "Invalid Fundraiser Account"
+    );
+    assert!(
+        contributor_state_account.owned_by(&crate::ID),
+        "Invalid Contributor Account"
+    );
+
+    check_contributor(contributor_state_account, fundraiser, contributor);
+
+    let current_time_unix = Clock::get()?.unix_timestamp;
+
+    let mut fundraiser_data = fundraiser.try_borrow_mut().unwrap();
+    let fundraiser_mutable =
+        bytemuck::from_bytes_mut::<Fundraiser>(&mut fundraiser_data[..Fundraiser::LEN]);
+
+    assert!(
+        fundraiser_mutable.is_vote_approved(),
+        "Milestones are approved by the approver"
+    );
+    assert!(
+        index < fundraiser_mutable.milestones_count[0] as usize,
+        "Invalid milestone"
+    );
+
+    let vote_duration = u64::from_le_bytes(fundraiser_mutable.vote_duration) as i64;
+    let milestone = &mut fundraiser_mutable.milestones[index];
+
+    assert!(
+        milestone.status[0] == MILESTONE_SUBMITTED,
+        "Milestone not submitted"
+    );
+
+    let voting_ends = i64::from_le_bytes(milestone.submitted_at) + vote_duration;
+    assert!(current_time_unix < voting_ends, "Voting closed");
+
+    // weight is locked until the vote closes
+    let mut contributor_state_data = contributor_state_account.try_borrow_mut().unwrap();
+    let contributor_mutable = bytemuck::from_bytes_mut::<Contributor>(&mut contributor_state_data);
+
+    let weight = u64::from_le_bytes(contributor_mutable.amount);
+    assert!(weight > 0, "Nothing to vote with");
+
+    if i64::from_le_bytes(contributor_mutable.locked_until) < voting_ends {
+        contributor_mutable.locked_until = voting_ends.to_le_bytes();
+    }
+
+    if approve {
+        let approve_weight = u64::from_le_bytes(milestone.approve_weight) + weight;
+        milestone.approve_weight = approve_weight.to_le_bytes();
+    } else {
+        let reject_weight = u64::from_le_bytes(milestone.reject_weight) + weight;
+        milestone.reject_weight = reject_weight.to_le_bytes();
+    }
+
+    drop(contributor_state_data);
+    drop(fundraiser_data);
+
+    // one vote per contributor per milestone - creating the pda fails if it exists
+    let milestone_seed = [index as u8];
+    let seed = [
+        b"vote",
+        fundraiser.address().as_ref(),
+        milestone_seed.as_ref(),
+        contributor.address().as_ref(),
+    ];
+    let (created_vote, vote_bump) = Address::find_program_address(&seed, &crate::ID);
+
+    assert!(&created_vote.eq(vote.address()), "Vote does not match");
+
+    let bump = vote_bump.to_le_bytes();
+    let vote_seeds = [
+        Seed::from(b"vote"),
+        Seed::from(fundraiser.address().as_ref()),
+        Seed::from(&milestone_seed),
+        Seed::from(contributor.address().as_ref()),
+        Seed::from(&bump),
+    ];
+
+    CreateAccount {
+        from: contributor,
+        lamports: Rent::get()?.minimum_balance_unchecked(Vote::LEN),
+        owner: &crate::ID,
+        space: Vote::LEN as u64,
+        to: vote,
+    }
+    .invoke_signed(&[Signer::from(&vote_seeds)])?;
+
+    let mut vote_data = vote.try_borrow_mut().unwrap();
+    let vote_mutable = bytemuck::from_bytes_mut::<Vote>(&mut vote_data);
+
+    vote_mutable.fundraiser = fundraiser.address().as_ref().try_into().unwrap();
+    vote_mutable.contributor = contributor.address().as_ref().try_into().unwrap();
+    vote_mutable.milestone = milestone_seed;
+    vote_mutable.approve = [approve as u8];
+    vote_mutable.weight = weight.to_le_bytes();
+
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use pinocchio::{AccountView, Address};
+
+use crate::state::{Config, Denylist};
+
+// config is the program config pda
+pub fn check_config(config: &AccountView) {
+    assert!(config.owned_by(&crate::ID), "Invalid Config Account");
+    assert!(
+        Address::find_program_address(&[b"config"], &crate::ID)
+            .0
+            .eq(config.address()),
+        "Config does not match"
+    );
+}
+
+// copy of the program config, once checked
+pub fn load_config(config: &AccountView) -> Config {
+    check_config(config);
+
+    let config_data = config.try_borrow().unwrap();
+    *bytemuck::from_bytes::<Config>(&config_data)
+}
+
+// denylist is the program denylist and none of `wallets` is on it, fails with `message`
+pub fn assert_not_denylisted(denylist: &AccountView, wallets: &[&Address], message: &str) {
+    assert!(denylist.owned_by(&crate::ID), "Invalid Denylist Account");
+    assert!(
+        Address::find_program_address(&[b"denylist"], &crate::ID)
+            .0
+            .eq(denylist.address()),
+        "Denylist does not match"
+    );
+
+    let denylist_data = denylist.try_borrow().unwrap();
+    let denylist_state = bytemuck::from_bytes::<Denylist>(&denylist_data);
+
+    assert!(
+        !wallets
+            .iter()
+            .any(|wallet| denylist_state.contains(wallet.as_ref())),
+        "{}",
+        message
+    );
+}
+
+// contributor state is the pda of `wallet` in `fundraiser`, returns its bump
+pub fn check_contributor(
+    contributor_state: &AccountView,
+    fundraiser: &AccountView,
+    wallet: &AccountView,
+) -> u8 {
+    let (contributor_address, contributor_bump) = Address::find_program_address(
+        &[
+            b"contributor",
+            fundraiser.address().as_ref(),
+            wallet.address().as_ref(),
+        ],
+        &crate::ID,
+    );
+    assert!(
+        contributor_address.eq(contributor_state.address()),
+        "Contributor does not match"
+    );
+
+    contributor_bump
+}
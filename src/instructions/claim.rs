@@ -0,0 +1,46 @@
+use pinocchio::{
+    error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    AccountView, ProgramResult,
+};
+
+use crate::instructions::{check_maker, pay_out};
+use crate::state::Fundraiser;
+
+// lump sum payout of a successful fundraiser without milestones
+// accounts: [maker, fundraiser, token program, ...multisig signers, ...(vault, ...recipient token accounts) per accepted mint]
+pub fn process_claim_instruction(accounts: &[AccountView]) -> ProgramResult {
+    let [maker, fundraiser, _token_program, rest @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    assert!(
+        fundraiser.owned_by(&crate::ID),
+        "Invalid Fundraiser Account"
+    );
+
+    let payout_accounts = {
+        let mut fundraiser_data = fundraiser.try_borrow_mut().unwrap();
+        let fundraiser_mutable =
+            bytemuck::from_bytes_mut::<Fundraiser>(&mut fundraiser_data[..Fundraiser::LEN]);
+
+        let payout_accounts = check_maker(maker, &fundraiser_mutable.maker, rest);
+
+        assert!(
+            fundraiser_mutable.is_successful(Clock::get()?.unix_timestamp),
+            "Fundraiser not successful"
+        );
+        assert!(
+            !fundraiser_mutable.has_milestones(),
+            "Funds are released per milestone"
+        );
+        assert!(!fundraiser_mutable.is_vesting(), "Funds are vested");
+        assert!(fundraiser_mutable.claimed[0] == 0, "Already claimed");
+
+        fundraiser_mutable.claimed = [1];
+
+        payout_accounts
+    };
+
+    pay_out(fundraiser, payout_accounts, 10_000)
+}
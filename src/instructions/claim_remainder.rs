@@ -0,0 +1,186 @@
+use bytemuck::Zeroable;
+use pinocchio::{
+    cpi::{Seed, Signer},
+    error::ProgramError,
+    AccountView, Address, ProgramResult,
+};
+use pinocchio_token::{
+    instructions::{Burn, Transfer},
+    state::TokenAccount,
+};
+
+use crate::instructions::{
+    assert_not_denylisted, check_contributor, freeze_receipts, load_config, thaw_receipts,
+};
+use crate::state::{
+    AcceptedMint, Contributor, Fundraiser, Proposal, MAX_ACCEPTED_MINTS, PROPOSAL_PASSED,
+};
+
+// refunds the contributor share of what was left in the vaults of an aborted fundraiser,
+// contributor amount over the total contributed, for every accepted mint
+// accounts: [contributor, fundraiser, contributor state, proposal, token program, config,
+//            denylist (only once created), ...(vault, contributor token account) per accepted mint,
+//            receipt mint, contributor receipt account if the fundraiser has a receipt mint]
+pub fn process_claim_remainder_instruction(accounts: &[AccountView]) -> ProgramResult {
+    let [contributor, fundraiser, contributor_state_account, proposal, _token_program, config, refund_accounts @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    assert!(contributor.is_signer(), "Contributor must be signer");
+    assert!(
+        fundraiser.owned_by(&crate::ID),
+        "Invalid Fundraiser Account"
+    );
+    assert!(
+        contributor_state_account.owned_by(&crate::ID),
+        "Invalid Contributor Account"
+    );
+    assert!(proposal.owned_by(&crate::ID), "Invalid Proposal Account");
+
+    check_contributor(contributor_state_account, fundraiser, contributor);
+
+    // refunds below are paid to contributor owned token accounts, never to a denylisted wallet
+    let refund_accounts = if load_config(config).has_denylist() {
+        let (denylist, refund_accounts) = refund_accounts.split_first().expect("Denylist missing");
+        assert_not_denylisted(
+            denylist,
+            &[contributor.address()],
+            "Refund destination denylisted",
+        );
+
+        refund_accounts
+    } else {
+        refund_accounts
+    };
+
+    let proposal_state = {
+        let proposal_data = proposal.try_borrow().unwrap();
+        *bytemuck::from_bytes::<Proposal>(&proposal_data)
+    };
+
+    assert!(
+        fundraiser
+            .address()
+            .eq(&Address::new_from_array(proposal_state.fundraiser)),
+        "Fundraiser does not match"
+    );
+    assert!(
+        proposal_state.status[0] == PROPOSAL_PASSED,
+        "Fundraiser not aborted"
+    );
+
+    // zeroing the amount makes the claim one shot
+    let weight = {
+        let mut contributor_state_data = contributor_state_account.try_borrow_mut().unwrap();
+        let contributor_mutable =
+            bytemuck::from_bytes_mut::<Contributor>(&mut contributor_state_data);
+
+        let weight = u64::from_le_bytes(contributor_mutable.amount);
+        assert!(weight > 0, "Nothing to claim");
+
+        contributor_mutable.amount = 0u64.to_le_bytes();
+        contributor_mutable.amounts = [[0u8; 8]; MAX_ACCEPTED_MINTS];
+
+        weight
+    };
+
+    // copy what we need so the fundraiser isn't borrowed while it signs
+    let (fundraiser_state, accepted_mints, mints_count) = {
+        let fundraiser_data = fundraiser.try_borrow().unwrap();
+        let fundraiser_state =
+            *bytemuck::from_bytes::<Fundraiser>(&fundraiser_data[..Fundraiser::LEN]);
+
+        let mut accepted_mints = [AcceptedMint::zeroed(); MAX_ACCEPTED_MINTS];
+        let table = Fundraiser::accepted_mints(&fundraiser_data);
+        accepted_mints[..table.len()].copy_from_slice(table);
+
+        (fundraiser_state, accepted_mints, table.len())
+    };
+
+    let bump = fundraiser_state.bump;
+    let fundraiser_seeds = [
+        Seed::from(b"fundraiser"),
+        Seed::from(fundraiser_state.creator.as_ref()),
+        Seed::from(&bump),
+    ];
+
+    // the refunded position is burnt from the contributor receipts
+    let refund_accounts = if fundraiser_state.has_receipt_mint() {
+        let (refund_accounts, receipt_accounts) = refund_accounts
+            .split_at_checked(mints_count * 2)
+            .expect("Every accepted mint vault must be passed");
+        let [receipt_mint, receipt_account] = receipt_accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        assert!(
+            receipt_mint
+                .address()
+                .eq(&Address::new_from_array(fundraiser_state.receipt_mint)),
+            "Receipt mint does not match"
+        );
+
+        thaw_receipts(receipt_account, receipt_mint, fundraiser, &fundraiser_seeds)?;
+
+        Burn {
+            account: receipt_account,
+            mint: receipt_mint,
+            authority: contributor,
+            amount: weight,
+        }
+        .invoke()?;
+
+        freeze_receipts(receipt_account, receipt_mint, fundraiser, &fundraiser_seeds)?;
+
+        refund_accounts
+    } else {
+        refund_accounts
+    };
+
+    assert!(
+        refund_accounts.len() == mints_count * 2,
+        "Every accepted mint vault must be passed"
+    );
+
+    let total_weight = u64::from_le_bytes(proposal_state.total_weight) as u128;
+
+    for ((accepted_mint, accounts), remaining) in accepted_mints[..mints_count]
+        .iter()
+        .zip(refund_accounts.chunks_exact(2))
+        .zip(proposal_state.remaining.iter())
+    {
+        let (vault, destination) = (&accounts[0], &accounts[1]);
+
+        assert!(
+            vault
+                .address()
+                .eq(&Address::new_from_array(accepted_mint.vault)),
+            "vault do not match"
+        );
+        assert!(
+            TokenAccount::from_account_view(destination)?
+                .owner()
+                .eq(contributor.address()),
+            "Destination not owned by contributor"
+        );
+
+        let amount =
+            (u64::from_le_bytes(*remaining) as u128 * weight as u128 / total_weight) as u64;
+
+        if amount == 0 {
+            continue;
+        }
+
+        Transfer {
+            amount,
+            authority: fundraiser,
+            from: vault,
+            to: destination,
+        }
+        .invoke_signed(&[Signer::from(&fundraiser_seeds)])?;
+    }
+
+    Ok(())
+}
@@ -1,35 +1,101 @@
-use core::cmp::Ordering;
-
+use bytemuck::{Pod, Zeroable};
 use pinocchio::{
-    cpi::{Seed, Signer},
+    cpi::{invoke_with_bounds, Seed, Signer},
     error::ProgramError,
+    instruction::{InstructionAccount, InstructionView},
     sysvars::{clock::Clock, rent::Rent, Sysvar},
     AccountView, Address, ProgramResult,
 };
 
+use pinocchio_associated_token_account::instructions::CreateIdempotent;
 use pinocchio_log::log;
 use pinocchio_system::instructions::CreateAccount;
-use pinocchio_token::{instructions::Transfer, state::TokenAccount};
+use pinocchio_token::{
+    instructions::{MintTo, Transfer},
+    state::TokenAccount,
+};
+
+use crate::instructions::{
+    assert_not_denylisted, check_contributor, freeze_receipts, load_config, thaw_receipts,
+    verify_attestation,
+};
+use crate::state::{
+    integer_sqrt, verify_allowlist_proof, Contributor, Fundraiser, Matching, PriceFeed, Round,
+    PRICE_FEED_MAGIC,
+};
 
-use crate::state::{Contributor, Fundraiser};
+// swap programs plugged into a deposit are called with
+// data: [SWAP_DISCRIMINATOR, amount_in (u64), min_out (u64)]
+// accounts: [contributor (signer), source ata, contributor ata (output), ...swap accounts]
+pub const SWAP_DISCRIMINATOR: u8 = 0;
+
+// max accounts a swap program can require on top of the three above
+pub const MAX_SWAP_ACCOUNTS: usize = 12;
+
+#[repr(C, packed)]
+#[derive(Pod, Zeroable, Clone, Copy)]
+pub struct ContributeData {
+    pub amount: [u8; 8],        // amount_in of the source token when swapping
+    pub min_out: [u8; 8],       // least mint_to_raise the swap must deliver
+    pub swap: [u8; 1],          // 0 - no swap, deposit straight from the contributor ata
+    pub swap_accounts: [u8; 1], // number of accounts the swap program needs
+}
+
+impl ContributeData {
+    pub const LEN: usize = core::mem::size_of::<ContributeData>();
+
+    pub fn to_bytes(&self) -> &[u8; Self::LEN] {
+        bytemuck::bytes_of(self).try_into().unwrap()
+    }
+}
 
 pub fn process_contribute_instruction(accounts: &[AccountView], data: &[u8]) -> ProgramResult {
-    let [contributor, mint_to_raise, fundraiser, contributor_state_account, contributor_ata, vault, system_program, token_program, associated_token_program, rent_sysvar, _extra @ ..] =
+    let [contributor, accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    contribute(contributor, contributor, accounts, data)
+}
+
+// gift / custodial deposit - the payer funds the transfer and the rent, the beneficiary gets the
+// contributor record and with it the refund and vote rights
+// accounts: [payer, beneficiary, ...deposit accounts after the contributor]
+pub fn process_contribute_for_instruction(accounts: &[AccountView], data: &[u8]) -> ProgramResult {
+    let [payer, beneficiary, accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    contribute(payer, beneficiary, accounts, data)
+}
+
+// `contributor` signs and pays, `beneficiary` is credited - the same wallet for a plain deposit
+fn contribute(
+    contributor: &AccountView,
+    beneficiary: &AccountView,
+    accounts: &[AccountView],
+    data: &[u8],
+) -> ProgramResult {
+    let [mint_to_raise, fundraiser, contributor_state_account, contributor_ata, vault, system_program, token_program, associated_token_program, rent_sysvar, config, remaining @ ..] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
-    let parsed_data = bytemuck::from_bytes::<Contributor>(&data[..Contributor::LEN]);
+    let parsed_data = bytemuck::from_bytes::<ContributeData>(&data[..ContributeData::LEN]);
 
     // ensure contributor is signer
 
     assert!(contributor.is_signer(), "Contributor must be signer");
 
+    // no deposit while the program is paused
+    let config_state = load_config(config);
+    assert!(!config_state.is_paused(), "Program paused");
+
     let fundraiser_data = fundraiser.try_borrow().unwrap();
 
     // ensure fundraiser exists and was created with this program_id
-    let fundraiser_as_state_account = bytemuck::from_bytes::<Fundraiser>(&*fundraiser_data);
+    let fundraiser_as_state_account =
+        bytemuck::from_bytes::<Fundraiser>(&fundraiser_data[..Fundraiser::LEN]);
 
     assert!(!fundraiser.is_data_empty(), "Fundraiser must exist");
     assert!(
@@ -38,41 +104,24 @@ pub fn process_contribute_instruction(accounts: &[AccountView], data: &[u8]) ->
     );
 
     // ensure time is still valid for contribution
-    let fundraising_ends_days = u8::from_le_bytes(fundraiser_as_state_account.duration);
-    let unix_time_fundraising_started =
-        u64::from_le_bytes(fundraiser_as_state_account.time_started);
-
     let current_time_unix = Clock::get()?.unix_timestamp;
 
-    let target_days_in_unix: u64 = u64::from(fundraising_ends_days) * 24 * 60 * 60;
-
-    let expiration_time = target_days_in_unix + unix_time_fundraising_started;
-
     assert!(
-        expiration_time > current_time_unix as u64,
+        fundraiser_as_state_account.end_time() > current_time_unix as u64,
         "Fundraising closed"
     );
+    assert!(
+        !fundraiser_as_state_account.is_paused(),
+        "Fundraiser paused"
+    );
+    assert!(
+        !fundraiser_as_state_account.is_pledge_mode(),
+        "Fundraiser takes pledges"
+    );
 
-    // ensure contributor ata exists - scoping to drop contributot_ata once done
-    {
-        let contributor_ata_as_state = TokenAccount::from_account_view(contributor_ata)
-            .map_err(|_| "Token Acccount does not exist")
-            .unwrap();
-
-        assert!(
-            contributor_ata_as_state.amount() > u64::from_le_bytes(parsed_data.amount),
-            "insufficient contributor balance"
-        );
-    }
-
-    // ensure mint to raise is same as one stored in fundraiser state
-
-    let compared_mint = mint_to_raise
-        .address()
-        .partial_cmp(&Address::new_from_array(
-            fundraiser_as_state_account.mint_to_raise,
-        ));
-    assert_eq!(compared_mint, Some(Ordering::Equal), "mint do not match");
+    // ensure mint to raise is one of the mints accepted by the fundraiser
+    let mint_index = Fundraiser::find_accepted_mint(&fundraiser_data, mint_to_raise.address())
+        .expect("mint not accepted");
 
     // compared another way 👀👀 10 CUs cheaper this way
     // assert!(
@@ -82,17 +131,225 @@ pub fn process_contribute_instruction(accounts: &[AccountView], data: &[u8]) ->
     //     "mint do not match"
     // );
 
-    // assert vault provided is correct
+    // assert vault provided is the one recorded for that mint
     assert!(
-        vault
-            .address()
-            .eq(&Address::new_from_array(fundraiser_as_state_account.vault,)),
+        vault.address().eq(&Address::new_from_array(
+            Fundraiser::accepted_mints(&fundraiser_data)[mint_index].vault,
+        )),
         "vault do not match"
     );
 
+    // private raises only take allowlisted wallets, the data carries
+    // [cap (u64, 0 - uncapped), ...proof nodes (32 bytes each)] right after the contribute data
+    let allowlist_cap = if fundraiser_as_state_account.has_allowlist() {
+        let allowlist_data = &data[ContributeData::LEN..];
+        assert!(
+            allowlist_data.len() >= 8 && (allowlist_data.len() - 8) % 32 == 0,
+            "Invalid allowlist proof"
+        );
+
+        let (cap, proof) = allowlist_data.split_at(8);
+        let cap = u64::from_le_bytes(cap.try_into().unwrap());
+
+        assert!(
+            verify_allowlist_proof(
+                &fundraiser_as_state_account.allowlist_root,
+                beneficiary.address().as_ref(),
+                cap,
+                proof,
+            ),
+            "Contributor not allowlisted"
+        );
+
+        cap
+    } else {
+        0
+    };
+
+    // kyc'd raises need an attestor signed (contributor, fundraiser, expiry) checked by an
+    // ed25519 instruction earlier in the transaction - the instructions sysvar is the last account
+    let remaining = if fundraiser_as_state_account.has_attestor() {
+        let (instructions_sysvar, remaining) =
+            remaining.split_last().expect("Instructions sysvar missing");
+
+        verify_attestation(
+            instructions_sysvar,
+            &fundraiser_as_state_account.attestor,
+            beneficiary.address(),
+            fundraiser.address(),
+            current_time_unix,
+        );
+
+        remaining
+    } else {
+        remaining
+    };
+
+    let amount = u64::from_le_bytes(parsed_data.amount);
+    let min_out = u64::from_le_bytes(parsed_data.min_out);
+
+    let mut remaining = remaining.iter();
+
+    // once the admin created the denylist it comes first - neither the contributor nor the
+    // credited wallet can be on it, refunds check it again on the way out
+    if config_state.has_denylist() {
+        let denylist = remaining.next().expect("Denylist missing");
+        assert_not_denylisted(
+            denylist,
+            &[contributor.address(), beneficiary.address()],
+            "Contributor denylisted",
+        );
+    }
+
+    // usd mode fundraisers need the mint price feed next
+    let price_feed_account = if fundraiser_as_state_account.is_usd_mode() {
+        Some(remaining.next().expect("Price feed missing"))
+    } else {
+        None
+    };
+
+    // amount of mint_to_raise that ends up in the vault
+    let deposit_amount = if parsed_data.swap[0] == 1 {
+        // swap path - [swap program, source ata, ...swap accounts]
+        let swap_program = remaining.next().expect("Swap program missing");
+        let source_ata = remaining.next().expect("Source ata missing");
+
+        let swap_accounts_count = parsed_data.swap_accounts[0] as usize;
+        assert!(
+            swap_accounts_count <= MAX_SWAP_ACCOUNTS,
+            "Too many swap accounts"
+        );
+
+        {
+            let source_ata_as_state = TokenAccount::from_account_view(source_ata)
+                .map_err(|_| "Token Acccount does not exist")
+                .unwrap();
+
+            assert!(
+                source_ata_as_state.amount() >= amount,
+                "insufficient contributor balance"
+            );
+        }
+
+        let balance_before = TokenAccount::from_account_view(contributor_ata)?.amount();
+
+        let swap_account_views: [&AccountView; MAX_SWAP_ACCOUNTS + 3] =
+            core::array::from_fn(|index| match index {
+                0 => contributor,
+                1 => source_ata,
+                2 => contributor_ata,
+                index if index - 3 < swap_accounts_count => {
+                    remaining.next().expect("Swap account missing")
+                }
+                _ => contributor,
+            });
+
+        let swap_instruction_accounts: [InstructionAccount; MAX_SWAP_ACCOUNTS + 3] =
+            core::array::from_fn(|index| {
+                let account = swap_account_views[index];
+                InstructionAccount::new(
+                    account.address(),
+                    account.is_writable(),
+                    account.is_signer(),
+                )
+            });
+
+        let mut swap_data = [0u8; 17];
+        swap_data[0] = SWAP_DISCRIMINATOR;
+        swap_data[1..9].copy_from_slice(&amount.to_le_bytes());
+        swap_data[9..17].copy_from_slice(&min_out.to_le_bytes());
+
+        invoke_with_bounds::<{ MAX_SWAP_ACCOUNTS + 3 }>(
+            &InstructionView {
+                program_id: swap_program.address(),
+                accounts: &swap_instruction_accounts[..swap_accounts_count + 3],
+                data: &swap_data,
+            },
+            &swap_account_views[..swap_accounts_count + 3],
+        )?;
+
+        // only what actually landed in the contributor ata counts - never trust the swap program
+        let swapped_amount = TokenAccount::from_account_view(contributor_ata)?
+            .amount()
+            .checked_sub(balance_before)
+            .expect("Swap took from the contributor ata");
+        assert!(swapped_amount >= min_out, "Slippage exceeded");
+
+        swapped_amount
+    } else {
+        // ensure contributor ata exists - scoping to drop contributot_ata once done
+        {
+            let contributor_ata_as_state = TokenAccount::from_account_view(contributor_ata)
+                .map_err(|_| "Token Acccount does not exist")
+                .unwrap();
+
+            assert!(
+                contributor_ata_as_state.amount() >= amount,
+                "insufficient contributor balance"
+            );
+        }
+
+        amount
+    };
+
+    // in usd mode the deposit is credited at its usd value, read from the mint price feed
+    let credited_amount = if let Some(price_feed_account) = price_feed_account {
+        let accepted_mint = &Fundraiser::accepted_mints(&fundraiser_data)[mint_index];
+
+        assert!(
+            price_feed_account.address().as_ref() == accepted_mint.price_feed.as_ref(),
+            "price feed do not match"
+        );
+        assert!(
+            price_feed_account.owned_by(&Address::new_from_array(config_state.oracle_program)),
+            "Price feed not owned by the oracle"
+        );
+
+        let price_feed_data = price_feed_account.try_borrow().unwrap();
+        let price_feed = bytemuck::from_bytes::<PriceFeed>(&price_feed_data[..PriceFeed::LEN]);
+
+        assert_eq!(
+            u32::from_le_bytes(price_feed.magic),
+            PRICE_FEED_MAGIC,
+            "Invalid price feed"
+        );
+
+        let price = i64::from_le_bytes(price_feed.price);
+        assert!(price > 0, "Invalid price");
+
+        // staleness check - a price published in the future is as untrusted as a stale one
+        let price_age = current_time_unix - i64::from_le_bytes(price_feed.publish_time);
+        assert!(price_age >= 0, "Price published in the future");
+        assert!(
+            price_age <= u64::from_le_bytes(fundraiser_as_state_account.max_price_age) as i64,
+            "Price is stale"
+        );
+
+        // confidence check - conf / price must stay under the configured bps
+        let conf = u64::from_le_bytes(price_feed.conf) as u128;
+        let max_conf_bps = u16::from_le_bytes(fundraiser_as_state_account.max_conf_bps) as u128;
+        assert!(
+            conf * 10_000 <= price as u128 * max_conf_bps,
+            "Price confidence too wide"
+        );
+
+        price_feed.to_usd(deposit_amount, accepted_mint.decimals[0])?
+    } else {
+        deposit_amount
+    };
+
+    assert!(deposit_amount > 0, "Nothing to deposit");
+
+    let has_matching = fundraiser_as_state_account.has_matching[0] == 1;
+    let round_address = fundraiser_as_state_account.round;
+    let has_round = fundraiser_as_state_account.has_round();
+
+    // release the fundraiser borrow, it gets mutably borrowed once funds are in
+    drop(fundraiser_data);
+
     // transfer to vault
     Transfer {
-        amount: u64::from_le_bytes(parsed_data.amount),
+        amount: deposit_amount,
         authority: &contributor,
         from: &contributor_ata,
         to: &vault,
@@ -100,25 +357,72 @@ pub fn process_contribute_instruction(accounts: &[AccountView], data: &[u8]) ->
     .invoke()?;
     log!("got here 👀");
 
+    // sponsor matching - [matching, matching vault] after any price feed / swap accounts
+    // only deposits in the primary mint get matched
+    let matched_amount = if has_matching && mint_index == 0 {
+        let matching = remaining.next().expect("Matching missing");
+        let matching_vault = remaining.next().expect("Matching vault missing");
+
+        assert!(matching.owned_by(&crate::ID), "Invalid Matching Account");
+
+        let mut matching_data = matching.try_borrow_mut().unwrap();
+        let matching_mutable = bytemuck::from_bytes_mut::<Matching>(&mut matching_data);
+
+        assert!(
+            fundraiser
+                .address()
+                .eq(&Address::new_from_array(matching_mutable.fundraiser)),
+            "Matching does not match"
+        );
+        assert!(
+            matching_vault
+                .address()
+                .eq(&Address::new_from_array(matching_mutable.matching_vault)),
+            "vault do not match"
+        );
+
+        let matched_amount = matching_mutable.match_for(deposit_amount);
+        let total_matched = u64::from_le_bytes(matching_mutable.matched_amount) + matched_amount;
+        matching_mutable.matched_amount = total_matched.to_le_bytes();
+
+        let bump = matching_mutable.bump;
+        drop(matching_data);
+
+        if matched_amount > 0 {
+            let matching_seeds = [
+                Seed::from(b"matching"),
+                Seed::from(fundraiser.address().as_ref()),
+                Seed::from(&bump),
+            ];
+
+            Transfer {
+                amount: matched_amount,
+                authority: matching,
+                from: matching_vault,
+                to: &vault,
+            }
+            .invoke_signed(&[Signer::from(&matching_seeds)])?;
+        }
+
+        matched_amount
+    } else {
+        0
+    };
+
     let rent = Rent::get()?;
     let minimum_balance = rent.minimum_balance_unchecked(Contributor::LEN);
 
-    let seed = [b"contributor", contributor.address().as_ref()];
-    let (created_contributor, contributor_bump) = Address::find_program_address(&seed, &crate::ID);
+    let contributor_bump = check_contributor(contributor_state_account, fundraiser, beneficiary);
 
     let bump = contributor_bump.to_le_bytes();
 
     let contributor_seeds = [
         Seed::from(b"contributor"),
-        Seed::from(contributor.address().as_ref()),
+        Seed::from(fundraiser.address().as_ref()),
+        Seed::from(beneficiary.address().as_ref()),
         Seed::from(&bump),
     ];
 
-    assert!(
-        &created_contributor.eq(contributor_state_account.address()),
-        "Contributor does not match"
-    );
-
     // if contributor state doesn't exist, create it
     if contributor_state_account.is_data_empty() {
         CreateAccount {
@@ -136,10 +440,168 @@ pub fn process_contribute_instruction(accounts: &[AccountView], data: &[u8]) ->
 
     let contributor_mutable = bytemuck::from_bytes_mut::<Contributor>(&mut contributor_state_data);
 
-    let current_amount = u64::from_le_bytes(contributor_mutable.amount);
-    let total_amount = current_amount + u64::from_le_bytes(parsed_data.amount);
+    let previous_amount = u64::from_le_bytes(contributor_mutable.amount);
+    let current_amount = previous_amount + credited_amount;
+    assert!(
+        allowlist_cap == 0 || current_amount <= allowlist_cap,
+        "Allowlist cap exceeded"
+    );
+    contributor_mutable.amount = current_amount.to_le_bytes();
+
+    let current_mint_amount = u64::from_le_bytes(contributor_mutable.amounts[mint_index]);
+    contributor_mutable.amounts[mint_index] = (current_mint_amount + deposit_amount).to_le_bytes();
+
+    // modify fundraiser totals - both the headline and the per mint one
+    let mut fundraiser_data = fundraiser.try_borrow_mut().unwrap();
+
+    let accepted_mint = &mut Fundraiser::accepted_mints_mut(&mut fundraiser_data)[mint_index];
+    let raised_for_mint = u64::from_le_bytes(accepted_mint.current_amount);
+    accepted_mint.current_amount =
+        (raised_for_mint + deposit_amount + matched_amount).to_le_bytes();
+
+    // matched tokens count towards the goal at the same rate as the deposit
+    let matched_credit =
+        (matched_amount as u128 * credited_amount as u128 / deposit_amount as u128) as u64;
+
+    let fundraiser_mutable =
+        bytemuck::from_bytes_mut::<Fundraiser>(&mut fundraiser_data[..Fundraiser::LEN]);
+    let raised = u64::from_le_bytes(fundraiser_mutable.current_amount);
+    fundraiser_mutable.current_amount = (raised + credited_amount + matched_credit).to_le_bytes();
 
-    contributor_mutable.amount = total_amount.to_le_bytes();
+    let contributed = u64::from_le_bytes(fundraiser_mutable.contributed_amount);
+    fundraiser_mutable.contributed_amount = (contributed + credited_amount).to_le_bytes();
+
+    let total_matched = u64::from_le_bytes(fundraiser_mutable.matched_amount) + matched_amount;
+    fundraiser_mutable.matched_amount = total_matched.to_le_bytes();
+
+    // reward tiers - the new total earns a receipt of the highest tier it reaches, unless
+    // the contributor already got one of that tier or above
+    let reached_tier = fundraiser_mutable
+        .tier_for(current_amount)
+        .filter(|tier_index| *tier_index >= contributor_mutable.tier[0] as usize);
+
+    if let Some(tier_index) = reached_tier {
+        let tier = &mut fundraiser_mutable.tiers[tier_index];
+        tier.minted = (u32::from_le_bytes(tier.minted) + 1).to_le_bytes();
+        contributor_mutable.tier = [tier_index as u8 + 1];
+    }
+
+    let (creator, fundraiser_bump) = (fundraiser_mutable.creator, fundraiser_mutable.bump);
+    let receipt_mint_address = fundraiser_mutable
+        .has_receipt_mint()
+        .then_some(fundraiser_mutable.receipt_mint);
+
+    // the fundraiser signs the receipt mints below
+    drop(contributor_state_data);
+    drop(fundraiser_data);
+
+    // quadratic funding round - [round] after any matching accounts
+    // keeps sum(sqrt(contributor amount)) up to date for the project
+    if has_round {
+        let round = remaining.next().expect("Round missing");
+
+        assert!(round.owned_by(&crate::ID), "Invalid Round Account");
+        assert!(
+            round.address().eq(&Address::new_from_array(round_address)),
+            "Round does not match"
+        );
+
+        let mut round_data = round.try_borrow_mut().unwrap();
+        let round_mutable = bytemuck::from_bytes_mut::<Round>(&mut round_data);
+
+        // deposits after the round ended don't earn matching
+        if i64::from_le_bytes(round_mutable.end_time) > current_time_unix {
+            let project_index = round_mutable
+                .find_project(fundraiser.address().as_ref())
+                .expect("Fundraiser not in round");
+
+            let project = &mut round_mutable.projects[project_index];
+            let sum_sqrt = u64::from_le_bytes(project.sum_sqrt) + integer_sqrt(current_amount)
+                - integer_sqrt(previous_amount);
+            project.sum_sqrt = sum_sqrt.to_le_bytes();
+        }
+    }
+
+    // [tier mint, contributor receipt account] after any round account, only when a tier is reached
+    if let Some(tier_index) = reached_tier {
+        let tier_mint = remaining.next().expect("Tier mint missing");
+        let receipt_account = remaining.next().expect("Receipt account missing");
+
+        assert!(
+            Address::find_program_address(
+                &[b"tier", fundraiser.address().as_ref(), &[tier_index as u8]],
+                &crate::ID
+            )
+            .0
+            .eq(tier_mint.address()),
+            "Tier mint does not match"
+        );
+
+        CreateIdempotent {
+            funding_account: contributor,
+            account: receipt_account,
+            wallet: beneficiary,
+            mint: tier_mint,
+            system_program,
+            token_program,
+        }
+        .invoke()?;
+
+        let fundraiser_seeds = [
+            Seed::from(b"fundraiser"),
+            Seed::from(creator.as_ref()),
+            Seed::from(&fundraiser_bump),
+        ];
+
+        MintTo {
+            mint: tier_mint,
+            account: receipt_account,
+            mint_authority: fundraiser,
+            amount: 1,
+        }
+        .invoke_signed(&[Signer::from(&fundraiser_seeds)])?;
+    }
+
+    // [receipt mint, contributor receipt account] last - receipts mirror the credited amount
+    if let Some(receipt_mint_address) = receipt_mint_address {
+        let receipt_mint = remaining.next().expect("Receipt mint missing");
+        let receipt_account = remaining.next().expect("Receipt account missing");
+
+        assert!(
+            receipt_mint
+                .address()
+                .eq(&Address::new_from_array(receipt_mint_address)),
+            "Receipt mint does not match"
+        );
+
+        CreateIdempotent {
+            funding_account: contributor,
+            account: receipt_account,
+            wallet: beneficiary,
+            mint: receipt_mint,
+            system_program,
+            token_program,
+        }
+        .invoke()?;
+
+        let fundraiser_seeds = [
+            Seed::from(b"fundraiser"),
+            Seed::from(creator.as_ref()),
+            Seed::from(&fundraiser_bump),
+        ];
+
+        thaw_receipts(receipt_account, receipt_mint, fundraiser, &fundraiser_seeds)?;
+
+        MintTo {
+            mint: receipt_mint,
+            account: receipt_account,
+            mint_authority: fundraiser,
+            amount: credited_amount,
+        }
+        .invoke_signed(&[Signer::from(&fundraiser_seeds)])?;
+
+        freeze_receipts(receipt_account, receipt_mint, fundraiser, &fundraiser_seeds)?;
+    }
 
     Ok(())
 }
@@ -0,0 +1,137 @@
+use pinocchio::{
+    cpi::{Seed, Signer},
+    error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    AccountView, Address, ProgramResult,
+};
+use pinocchio_token::{instructions::Transfer, state::TokenAccount};
+
+use crate::state::{Fundraiser, Round, MAX_ROUND_PROJECTS};
+
+// permissionless - once the round ended, split the pool between projects
+// proportionally to (sum of sqrt(contribution))^2, then release their payouts
+// accounts: [round, pool vault, token program, ...(fundraiser, vault) for every project in order]
+pub fn process_distribute_matching_instruction(accounts: &[AccountView]) -> ProgramResult {
+    let [round, pool_vault, _token_program, projects @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    assert!(round.owned_by(&crate::ID), "Invalid Round Account");
+
+    let mut round_data = round.try_borrow_mut().unwrap();
+    let round_mutable = bytemuck::from_bytes_mut::<Round>(&mut round_data);
+
+    assert!(
+        i64::from_le_bytes(round_mutable.end_time) <= Clock::get()?.unix_timestamp,
+        "Round still running"
+    );
+    assert!(
+        round_mutable.distributed[0] == 0,
+        "Round already distributed"
+    );
+    assert!(
+        pool_vault
+            .address()
+            .eq(&Address::new_from_array(round_mutable.pool_vault)),
+        "vault do not match"
+    );
+    assert!(
+        projects.len() == round_mutable.projects().len() * 2,
+        "Every project must be passed"
+    );
+
+    round_mutable.distributed = [1];
+
+    let pool = TokenAccount::from_account_view(pool_vault)?.amount() as u128;
+
+    let round_state = *round_mutable;
+    drop(round_data);
+
+    let current_time_unix = Clock::get()?.unix_timestamp;
+
+    // a project aborted or already ended below its goal gets nothing, its weight goes to the
+    // others - every project is released for payouts either way
+    let mut weights = [0u128; MAX_ROUND_PROJECTS];
+    for ((project, accounts), weight) in round_state
+        .projects()
+        .iter()
+        .zip(projects.chunks_exact(2))
+        .zip(weights.iter_mut())
+    {
+        let (fundraiser, vault) = (&accounts[0], &accounts[1]);
+
+        assert!(
+            fundraiser
+                .address()
+                .eq(&Address::new_from_array(project.fundraiser)),
+            "Fundraiser does not match"
+        );
+        assert!(
+            vault.address().eq(&Address::new_from_array(project.vault)),
+            "vault do not match"
+        );
+
+        let mut fundraiser_data = fundraiser.try_borrow_mut().unwrap();
+        let fundraiser_mutable =
+            bytemuck::from_bytes_mut::<Fundraiser>(&mut fundraiser_data[..Fundraiser::LEN]);
+
+        fundraiser_mutable.round_distributed = [1];
+
+        let failed = fundraiser_mutable.is_aborted()
+            || (fundraiser_mutable.end_time() <= current_time_unix as u64
+                && !fundraiser_mutable.is_successful(current_time_unix));
+
+        if !failed {
+            let sum_sqrt = u64::from_le_bytes(project.sum_sqrt) as u128;
+            *weight = sum_sqrt * sum_sqrt;
+        }
+    }
+
+    let total_weight: u128 = weights.iter().sum();
+
+    if total_weight == 0 {
+        return Ok(());
+    }
+
+    let round_seeds = [
+        Seed::from(b"round"),
+        Seed::from(round_state.creator.as_ref()),
+        Seed::from(&round_state.bump),
+    ];
+
+    for (accounts, weight) in projects.chunks_exact(2).zip(weights.iter()) {
+        let (fundraiser, vault) = (&accounts[0], &accounts[1]);
+
+        let share = (pool * weight / total_weight) as u64;
+
+        if share == 0 {
+            continue;
+        }
+
+        Transfer {
+            amount: share,
+            authority: round,
+            from: pool_vault,
+            to: vault,
+        }
+        .invoke_signed(&[Signer::from(&round_seeds)])?;
+
+        // round funds count towards the goal like any matched amount
+        let mut fundraiser_data = fundraiser.try_borrow_mut().unwrap();
+
+        let primary_mint = &mut Fundraiser::accepted_mints_mut(&mut fundraiser_data)[0];
+        let raised_for_mint = u64::from_le_bytes(primary_mint.current_amount);
+        primary_mint.current_amount = (raised_for_mint + share).to_le_bytes();
+
+        let fundraiser_mutable =
+            bytemuck::from_bytes_mut::<Fundraiser>(&mut fundraiser_data[..Fundraiser::LEN]);
+
+        let raised = u64::from_le_bytes(fundraiser_mutable.current_amount);
+        fundraiser_mutable.current_amount = (raised + share).to_le_bytes();
+
+        let matched = u64::from_le_bytes(fundraiser_mutable.matched_amount);
+        fundraiser_mutable.matched_amount = (matched + share).to_le_bytes();
+    }
+
+    Ok(())
+}
@@ -0,0 +1,89 @@
+use pinocchio::{
+    error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    AccountView, Address, ProgramResult,
+};
+use pinocchio_token::state::TokenAccount;
+
+use crate::state::{
+    Fundraiser, Proposal, ABORT_SUPERMAJORITY_BPS, PROPOSAL_OPEN, PROPOSAL_PASSED,
+    PROPOSAL_REJECTED,
+};
+
+// permissionless - closes the kill switch vote once its window ended. On a supermajority the
+// fundraiser is aborted and every vault balance is snapshotted for the pro-rata claims
+// accounts: [fundraiser, proposal, ...vault per accepted mint]
+pub fn process_finalize_abort_instruction(accounts: &[AccountView]) -> ProgramResult {
+    let [fundraiser, proposal, vaults @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    assert!(
+        fundraiser.owned_by(&crate::ID),
+        "Invalid Fundraiser Account"
+    );
+    assert!(proposal.owned_by(&crate::ID), "Invalid Proposal Account");
+
+    let mut proposal_data = proposal.try_borrow_mut().unwrap();
+    let proposal_mutable = bytemuck::from_bytes_mut::<Proposal>(&mut proposal_data);
+
+    assert!(
+        fundraiser
+            .address()
+            .eq(&Address::new_from_array(proposal_mutable.fundraiser)),
+        "Fundraiser does not match"
+    );
+    assert!(
+        proposal_mutable.status[0] == PROPOSAL_OPEN,
+        "Proposal not open"
+    );
+    assert!(
+        Clock::get()?.unix_timestamp >= i64::from_le_bytes(proposal_mutable.ends_at),
+        "Voting still open"
+    );
+
+    let mut fundraiser_data = fundraiser.try_borrow_mut().unwrap();
+    let fundraiser_mutable =
+        bytemuck::from_bytes_mut::<Fundraiser>(&mut fundraiser_data[..Fundraiser::LEN]);
+
+    let total_weight = u64::from_le_bytes(fundraiser_mutable.contributed_amount);
+    let abort_weight = u64::from_le_bytes(proposal_mutable.abort_weight) as u128;
+
+    if abort_weight * 10_000 < total_weight as u128 * ABORT_SUPERMAJORITY_BPS {
+        proposal_mutable.status = [PROPOSAL_REJECTED];
+        return Ok(());
+    }
+
+    fundraiser_mutable.aborted = [1];
+    drop(fundraiser_data);
+
+    let fundraiser_data = fundraiser.try_borrow().unwrap();
+    let accepted_mints = Fundraiser::accepted_mints(&fundraiser_data);
+
+    assert!(
+        vaults.len() == accepted_mints.len(),
+        "Every accepted mint vault must be passed"
+    );
+
+    for ((accepted_mint, vault), remaining) in accepted_mints
+        .iter()
+        .zip(vaults.iter())
+        .zip(proposal_mutable.remaining.iter_mut())
+    {
+        assert!(
+            vault
+                .address()
+                .eq(&Address::new_from_array(accepted_mint.vault)),
+            "vault do not match"
+        );
+
+        *remaining = TokenAccount::from_account_view(vault)?
+            .amount()
+            .to_le_bytes();
+    }
+
+    proposal_mutable.total_weight = total_weight.to_le_bytes();
+    proposal_mutable.status = [PROPOSAL_PASSED];
+
+    Ok(())
+}
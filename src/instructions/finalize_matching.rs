@@ -0,0 +1,134 @@
+use pinocchio::{
+    cpi::{Seed, Signer},
+    error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    AccountView, Address, ProgramResult,
+};
+use pinocchio_token::{instructions::Transfer, state::TokenAccount};
+
+use crate::state::{Fundraiser, Matching};
+
+// permissionless - once the fundraiser ended, unused matching funds go back to the sponsor
+// if the raise failed, the matched funds already in the vault go back too
+// accounts: [fundraiser, matching, matching vault, sponsor ata, token program, vault]
+pub fn process_finalize_matching_instruction(accounts: &[AccountView]) -> ProgramResult {
+    let [fundraiser, matching, matching_vault, sponsor_ata, _token_program, vault, _extra @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    assert!(
+        fundraiser.owned_by(&crate::ID),
+        "Invalid Fundraiser Account"
+    );
+    assert!(matching.owned_by(&crate::ID), "Invalid Matching Account");
+
+    let (failed, creator, fundraiser_bump) = {
+        let fundraiser_data = fundraiser.try_borrow().unwrap();
+        let fundraiser_as_state_account =
+            bytemuck::from_bytes::<Fundraiser>(&fundraiser_data[..Fundraiser::LEN]);
+
+        let now = Clock::get()?.unix_timestamp;
+        assert!(
+            fundraiser_as_state_account.end_time() <= now as u64,
+            "Fundraising still running"
+        );
+        assert!(
+            vault
+                .address()
+                .eq(&Address::new_from_array(fundraiser_as_state_account.vault)),
+            "vault do not match"
+        );
+
+        (
+            !fundraiser_as_state_account.is_successful(now),
+            fundraiser_as_state_account.creator,
+            fundraiser_as_state_account.bump,
+        )
+    };
+
+    let mut matching_data = matching.try_borrow_mut().unwrap();
+    let matching_as_state_account = bytemuck::from_bytes_mut::<Matching>(&mut matching_data);
+
+    assert!(
+        fundraiser.address().eq(&Address::new_from_array(
+            matching_as_state_account.fundraiser
+        )),
+        "Fundraiser does not match"
+    );
+    assert!(
+        matching_vault.address().eq(&Address::new_from_array(
+            matching_as_state_account.matching_vault
+        )),
+        "vault do not match"
+    );
+
+    let leftover = {
+        let sponsor_ata_as_state = TokenAccount::from_account_view(sponsor_ata)?;
+        assert!(
+            sponsor_ata_as_state
+                .owner()
+                .eq(&Address::new_from_array(matching_as_state_account.sponsor)),
+            "Sponsor does not match"
+        );
+
+        TokenAccount::from_account_view(matching_vault)?.amount()
+    };
+
+    // a failed raise hands the matched funds back, zeroing them makes it one-shot
+    let returned = if failed {
+        let matched = u64::from_le_bytes(matching_as_state_account.matched_amount);
+        matching_as_state_account.matched_amount = 0u64.to_le_bytes();
+        matched
+    } else {
+        0
+    };
+
+    let bump = matching_as_state_account.bump;
+    drop(matching_data);
+    let matching_seeds = [
+        Seed::from(b"matching"),
+        Seed::from(fundraiser.address().as_ref()),
+        Seed::from(&bump),
+    ];
+
+    Transfer {
+        amount: leftover,
+        authority: matching,
+        from: matching_vault,
+        to: sponsor_ata,
+    }
+    .invoke_signed(&[Signer::from(&matching_seeds)])?;
+
+    if returned > 0 {
+        {
+            let mut fundraiser_data = fundraiser.try_borrow_mut().unwrap();
+            let fundraiser_mutable =
+                bytemuck::from_bytes_mut::<Fundraiser>(&mut fundraiser_data[..Fundraiser::LEN]);
+            fundraiser_mutable.matched_amount =
+                (u64::from_le_bytes(fundraiser_mutable.matched_amount) - returned).to_le_bytes();
+
+            // matching only ever lands in the primary mint
+            let primary_mint = &mut Fundraiser::accepted_mints_mut(&mut fundraiser_data)[0];
+            primary_mint.current_amount =
+                (u64::from_le_bytes(primary_mint.current_amount) - returned).to_le_bytes();
+        }
+
+        let fundraiser_seeds = [
+            Seed::from(b"fundraiser"),
+            Seed::from(creator.as_ref()),
+            Seed::from(&fundraiser_bump),
+        ];
+
+        Transfer {
+            amount: returned,
+            authority: fundraiser,
+            from: vault,
+            to: sponsor_ata,
+        }
+        .invoke_signed(&[Signer::from(&fundraiser_seeds)])?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,154 @@
+use bytemuck::{Pod, Zeroable};
+use pinocchio::{
+    cpi::{Seed, Signer},
+    error::ProgramError,
+    sysvars::{clock::Clock, rent::Rent, Sysvar},
+    AccountView, Address, ProgramResult,
+};
+use pinocchio_associated_token_account::instructions::Create;
+use pinocchio_system::instructions::CreateAccount;
+use pinocchio_token::instructions::Transfer;
+
+use crate::instructions::check_maker;
+use crate::state::{Fundraiser, Matching};
+
+#[repr(C, packed)]
+#[derive(Pod, Zeroable, Clone, Copy)]
+pub struct MatchingData {
+    pub ratio_bps: [u8; 2],
+    pub cap: [u8; 8],
+}
+
+impl MatchingData {
+    pub const LEN: usize = core::mem::size_of::<MatchingData>();
+
+    pub fn to_bytes(&self) -> &[u8; Self::LEN] {
+        bytemuck::bytes_of(self).try_into().unwrap()
+    }
+}
+
+// sponsor locks a matching pool on a running fundraiser, the maker co-signs so nobody can
+// squat the single matching slot with terms they didn't agree to
+// accounts: [sponsor, mint, fundraiser, matching, sponsor ata, matching vault, system program,
+//            token program, associated token program, maker, ...multisig signers]
+pub fn process_fund_matching_instruction(accounts: &[AccountView], data: &[u8]) -> ProgramResult {
+    let [sponsor, mint_to_raise, fundraiser, matching, sponsor_ata, matching_vault, system_program, token_program, _associated_token_program, maker, signers @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let parsed_data = bytemuck::from_bytes::<MatchingData>(&data[..MatchingData::LEN]);
+
+    assert!(sponsor.is_signer(), "Sponsor must be signer");
+    assert!(
+        u16::from_le_bytes(parsed_data.ratio_bps) > 0,
+        "Ratio must be positive"
+    );
+
+    // ensure fundraiser exists and is still running
+    {
+        assert!(!fundraiser.is_data_empty(), "Fundraiser must exist");
+        assert!(
+            fundraiser.owned_by(&crate::ID),
+            "Invalid Fundraiser Account"
+        );
+
+        let fundraiser_data = fundraiser.try_borrow().unwrap();
+        let fundraiser_as_state_account =
+            bytemuck::from_bytes::<Fundraiser>(&fundraiser_data[..Fundraiser::LEN]);
+
+        check_maker(maker, &fundraiser_as_state_account.maker, signers);
+        assert!(
+            fundraiser_as_state_account.end_time() > Clock::get()?.unix_timestamp as u64,
+            "Fundraising closed"
+        );
+
+        // a matched share can't follow a contribution out of the raise
+        assert!(
+            !fundraiser_as_state_account.allows_early_withdraw(),
+            "Early withdraw fundraisers can't be matched"
+        );
+        // pledges move no tokens until settle, there is no deposit to match against
+        assert!(
+            !fundraiser_as_state_account.is_pledge_mode(),
+            "Pledge mode fundraisers can't be matched"
+        );
+
+        // matching is paid in the primary mint only
+        assert!(
+            mint_to_raise.address().eq(&Address::new_from_array(
+                fundraiser_as_state_account.mint_to_raise,
+            )),
+            "mint do not match"
+        );
+    }
+
+    assert!(matching.is_data_empty(), "Matching already funded");
+    assert!(
+        matching_vault.is_data_empty(),
+        "Vault is already initialized"
+    );
+
+    let seed = [b"matching", fundraiser.address().as_ref()];
+    let (created_matching, matching_bump) = Address::find_program_address(&seed, &crate::ID);
+
+    assert!(
+        &created_matching.eq(matching.address()),
+        "Matching does not match"
+    );
+
+    let bump = matching_bump.to_le_bytes();
+
+    let matching_seeds = [
+        Seed::from(b"matching"),
+        Seed::from(fundraiser.address().as_ref()),
+        Seed::from(&bump),
+    ];
+
+    CreateAccount {
+        from: sponsor,
+        lamports: Rent::get()?.minimum_balance_unchecked(Matching::LEN),
+        owner: &crate::ID,
+        space: Matching::LEN as u64,
+        to: matching,
+    }
+    .invoke_signed(&[Signer::from(&matching_seeds)])?;
+
+    Create {
+        funding_account: sponsor,
+        system_program,
+        token_program,
+        wallet: matching,
+        account: matching_vault,
+        mint: mint_to_raise,
+    }
+    .invoke()?;
+
+    // the whole cap is locked upfront
+    Transfer {
+        amount: u64::from_le_bytes(parsed_data.cap),
+        authority: sponsor,
+        from: sponsor_ata,
+        to: matching_vault,
+    }
+    .invoke()?;
+
+    let mut matching_data = matching.try_borrow_mut().unwrap();
+    let matching_mutable = bytemuck::from_bytes_mut::<Matching>(&mut matching_data);
+
+    matching_mutable.sponsor = sponsor.address().as_ref().try_into().unwrap();
+    matching_mutable.fundraiser = fundraiser.address().as_ref().try_into().unwrap();
+    matching_mutable.matching_vault = matching_vault.address().as_ref().try_into().unwrap();
+    matching_mutable.ratio_bps = parsed_data.ratio_bps;
+    matching_mutable.cap = parsed_data.cap;
+    matching_mutable.matched_amount = 0u64.to_le_bytes();
+    matching_mutable.bump = bump;
+
+    let mut fundraiser_data = fundraiser.try_borrow_mut().unwrap();
+    let fundraiser_mutable =
+        bytemuck::from_bytes_mut::<Fundraiser>(&mut fundraiser_data[..Fundraiser::LEN]);
+    fundraiser_mutable.has_matching = [1];
+
+    Ok(())
+}
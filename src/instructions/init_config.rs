@@ -0,0 +1,112 @@
+use bytemuck::{Pod, Zeroable};
+use pinocchio::{
+    cpi::{Seed, Signer},
+    error::ProgramError,
+    sysvars::{rent::Rent, Sysvar},
+    AccountView, Address, ProgramResult,
+};
+use pinocchio_system::instructions::CreateAccount;
+
+use crate::state::Config;
+
+// BPFLoaderUpgradeab1e11111111111111111111111
+const BPF_LOADER_UPGRADEABLE_ID: Address = Address::new_from_array([
+    2, 168, 246, 145, 78, 136, 161, 176, 226, 16, 21, 62, 247, 99, 174, 43, 0, 194, 185, 61, 22,
+    193, 36, 210, 192, 83, 122, 16, 4, 128, 0, 0,
+]);
+
+// program data account: [tag (u32) = 3, slot (u64), authority option (1), authority (32)]
+const PROGRAM_DATA_TAG: u32 = 3;
+const PROGRAM_DATA_AUTHORITY_OFFSET: usize = 12;
+
+#[repr(C, packed)]
+#[derive(Pod, Zeroable, Clone, Copy)]
+pub struct ConfigData {
+    pub fee_bps: [u8; 2],
+    pub fee_recipient: [u8; 32],
+    pub oracle_program: [u8; 32],
+}
+
+impl ConfigData {
+    pub const LEN: usize = core::mem::size_of::<ConfigData>();
+
+    pub fn to_bytes(&self) -> &[u8; Self::LEN] {
+        bytemuck::bytes_of(self).try_into().unwrap()
+    }
+}
+
+// creates the program config, only the program upgrade authority can and it becomes the admin
+// accounts: [admin, config, system program, program data]
+pub fn process_init_config_instruction(accounts: &[AccountView], data: &[u8]) -> ProgramResult {
+    let [admin, config, _system_program, program_data, _extra @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let parsed_data = bytemuck::from_bytes::<ConfigData>(&data[..ConfigData::LEN]);
+
+    assert!(admin.is_signer(), "Admin must be signer");
+    assert!(
+        u16::from_le_bytes(parsed_data.fee_bps) <= 10_000,
+        "Invalid fee"
+    );
+    assert!(config.is_data_empty(), "Config already initialized");
+
+    // whoever deploys the program sets it up, nobody can front-run the config
+    {
+        assert!(
+            program_data.owned_by(&BPF_LOADER_UPGRADEABLE_ID),
+            "Invalid Program Data Account"
+        );
+        assert!(
+            Address::find_program_address(&[crate::ID.as_ref()], &BPF_LOADER_UPGRADEABLE_ID)
+                .0
+                .eq(program_data.address()),
+            "Program data does not match"
+        );
+
+        let program_data_bytes = program_data.try_borrow().unwrap();
+        let header = program_data_bytes
+            .get(..PROGRAM_DATA_AUTHORITY_OFFSET + 33)
+            .ok_or(ProgramError::InvalidAccountData)?;
+
+        assert!(
+            u32::from_le_bytes(header[..4].try_into().unwrap()) == PROGRAM_DATA_TAG,
+            "Invalid Program Data Account"
+        );
+        assert!(
+            header[PROGRAM_DATA_AUTHORITY_OFFSET] == 1
+                && header[PROGRAM_DATA_AUTHORITY_OFFSET + 1..] == *admin.address().as_ref(),
+            "Admin is not the upgrade authority"
+        );
+    }
+
+    let (created_config, config_bump) = Address::find_program_address(&[b"config"], &crate::ID);
+
+    assert!(
+        &created_config.eq(config.address()),
+        "Config does not match"
+    );
+
+    let bump = config_bump.to_le_bytes();
+    let config_seeds = [Seed::from(b"config"), Seed::from(&bump)];
+
+    CreateAccount {
+        from: admin,
+        lamports: Rent::get()?.minimum_balance_unchecked(Config::LEN),
+        owner: &crate::ID,
+        space: Config::LEN as u64,
+        to: config,
+    }
+    .invoke_signed(&[Signer::from(&config_seeds)])?;
+
+    let mut config_data = config.try_borrow_mut().unwrap();
+    let config_mutable = bytemuck::from_bytes_mut::<Config>(&mut config_data);
+
+    config_mutable.admin = admin.address().as_ref().try_into().unwrap();
+    config_mutable.fee_bps = parsed_data.fee_bps;
+    config_mutable.fee_recipient = parsed_data.fee_recipient;
+    config_mutable.oracle_program = parsed_data.oracle_program;
+    config_mutable.bump = bump;
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use pinocchio::{
+    cpi::{Seed, Signer},
+    error::ProgramError,
+    sysvars::{rent::Rent, Sysvar},
+    AccountView, Address, ProgramResult,
+};
+use pinocchio_system::instructions::CreateAccount;
+
+use crate::instructions::check_config;
+use crate::state::{Config, Denylist};
+
+// admin creates the program wide denylist, every deposit is checked against it from then on
+// accounts: [admin, config, denylist, system program]
+pub fn process_init_denylist_instruction(accounts: &[AccountView]) -> ProgramResult {
+    let [admin, config, denylist, _system_program, _extra @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    assert!(admin.is_signer(), "Admin must be signer");
+    check_config(config);
+    assert!(denylist.is_data_empty(), "Denylist already initialized");
+
+    let mut config_data = config.try_borrow_mut().unwrap();
+    let config_mutable = bytemuck::from_bytes_mut::<Config>(&mut config_data);
+
+    assert!(
+        admin
+            .address()
+            .eq(&Address::new_from_array(config_mutable.admin)),
+        "Admin does not match"
+    );
+
+    let (created_denylist, denylist_bump) =
+        Address::find_program_address(&[b"denylist"], &crate::ID);
+
+    assert!(
+        &created_denylist.eq(denylist.address()),
+        "Denylist does not match"
+    );
+
+    let bump = denylist_bump.to_le_bytes();
+    let denylist_seeds = [Seed::from(b"denylist"), Seed::from(&bump)];
+
+    CreateAccount {
+        from: admin,
+        lamports: Rent::get()?.minimum_balance_unchecked(Denylist::LEN),
+        owner: &crate::ID,
+        space: Denylist::LEN as u64,
+        to: denylist,
+    }
+    .invoke_signed(&[Signer::from(&denylist_seeds)])?;
+
+    let mut denylist_data = denylist.try_borrow_mut().unwrap();
+    let denylist_mutable = bytemuck::from_bytes_mut::<Denylist>(&mut denylist_data);
+
+    denylist_mutable.bump = bump;
+    config_mutable.has_denylist = [1];
+
+    Ok(())
+}
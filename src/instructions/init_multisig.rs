@@ -0,0 +1,117 @@
+use bytemuck::{Pod, Zeroable};
+use pinocchio::{
+    cpi::{Seed, Signer},
+    error::ProgramError,
+    sysvars::{rent::Rent, Sysvar},
+    AccountView, Address, ProgramResult,
+};
+use pinocchio_system::instructions::CreateAccount;
+
+use crate::instructions::check_maker;
+use crate::state::{Fundraiser, Multisig, MAX_MULTISIG_SIGNERS};
+
+#[repr(C, packed)]
+#[derive(Pod, Zeroable, Clone, Copy)]
+pub struct MultisigData {
+    pub threshold: [u8; 1],
+    pub signers_count: [u8; 1],
+    pub signers: [[u8; 32]; MAX_MULTISIG_SIGNERS],
+}
+
+impl MultisigData {
+    pub const LEN: usize = core::mem::size_of::<MultisigData>();
+
+    pub fn to_bytes(&self) -> &[u8; Self::LEN] {
+        bytemuck::bytes_of(self).try_into().unwrap()
+    }
+}
+
+// maker hands control to an M-of-N signer set, the multisig account becomes the maker
+// accounts: [maker, fundraiser, multisig, system program, ...multisig signers]
+pub fn process_init_multisig_instruction(accounts: &[AccountView], data: &[u8]) -> ProgramResult {
+    let [maker, fundraiser, multisig, _system_program, signers @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let parsed_data = bytemuck::from_bytes::<MultisigData>(&data[..MultisigData::LEN]);
+
+    let signers_count = parsed_data.signers_count[0] as usize;
+    let threshold = parsed_data.threshold[0] as usize;
+
+    assert!(
+        signers_count > 0 && signers_count <= MAX_MULTISIG_SIGNERS,
+        "Invalid multisig signers"
+    );
+    assert!(
+        threshold > 0 && threshold <= signers_count,
+        "Invalid multisig threshold"
+    );
+
+    let members = &parsed_data.signers[..signers_count];
+    assert!(
+        members
+            .iter()
+            .enumerate()
+            .all(|(index, member)| !members[..index].contains(member)),
+        "Duplicate multisig signer"
+    );
+
+    assert!(
+        fundraiser.owned_by(&crate::ID),
+        "Invalid Fundraiser Account"
+    );
+
+    {
+        let fundraiser_data = fundraiser.try_borrow().unwrap();
+        let fundraiser_state =
+            bytemuck::from_bytes::<Fundraiser>(&fundraiser_data[..Fundraiser::LEN]);
+
+        check_maker(maker, &fundraiser_state.maker, signers);
+    }
+
+    let seed = [b"multisig", fundraiser.address().as_ref()];
+    let (created_multisig, multisig_bump) = Address::find_program_address(&seed, &crate::ID);
+
+    assert!(
+        &created_multisig.eq(multisig.address()),
+        "Multisig does not match"
+    );
+
+    let bump = multisig_bump.to_le_bytes();
+    let multisig_seeds = [
+        Seed::from(b"multisig"),
+        Seed::from(fundraiser.address().as_ref()),
+        Seed::from(&bump),
+    ];
+
+    // a wallet maker pays the rent, a multisig one is replaced by a new set
+    if multisig.is_data_empty() {
+        CreateAccount {
+            from: maker,
+            lamports: Rent::get()?.minimum_balance_unchecked(Multisig::LEN),
+            owner: &crate::ID,
+            space: Multisig::LEN as u64,
+            to: multisig,
+        }
+        .invoke_signed(&[Signer::from(&multisig_seeds)])?;
+    }
+
+    let mut multisig_data = multisig.try_borrow_mut().unwrap();
+    let multisig_mutable = bytemuck::from_bytes_mut::<Multisig>(&mut multisig_data);
+
+    multisig_mutable.fundraiser = fundraiser.address().as_ref().try_into().unwrap();
+    multisig_mutable.threshold = parsed_data.threshold;
+    multisig_mutable.signers_count = parsed_data.signers_count;
+    multisig_mutable.signers = parsed_data.signers;
+    multisig_mutable.bump = bump;
+
+    let mut fundraiser_data = fundraiser.try_borrow_mut().unwrap();
+    let fundraiser_mutable =
+        bytemuck::from_bytes_mut::<Fundraiser>(&mut fundraiser_data[..Fundraiser::LEN]);
+
+    fundraiser_mutable.maker = multisig.address().as_ref().try_into().unwrap();
+    // a transfer proposed by the previous maker must not bypass the signer set
+    fundraiser_mutable.pending_maker = [0u8; 32];
+
+    Ok(())
+}
@@ -0,0 +1,103 @@
+use bytemuck::{Pod, Zeroable};
+use pinocchio::{
+    cpi::{Seed, Signer},
+    error::ProgramError,
+    sysvars::{clock::Clock, rent::Rent, Sysvar},
+    AccountView, Address, ProgramResult,
+};
+use pinocchio_associated_token_account::instructions::Create;
+use pinocchio_system::instructions::CreateAccount;
+use pinocchio_token::{instructions::Transfer, state::Mint};
+
+use crate::state::Round;
+
+#[repr(C, packed)]
+#[derive(Pod, Zeroable, Clone, Copy)]
+pub struct RoundData {
+    pub pool_amount: [u8; 8],
+    pub end_time: [u8; 8], // i64 unix timestamp
+}
+
+impl RoundData {
+    pub const LEN: usize = core::mem::size_of::<RoundData>();
+
+    pub fn to_bytes(&self) -> &[u8; Self::LEN] {
+        bytemuck::bytes_of(self).try_into().unwrap()
+    }
+}
+
+pub fn process_init_round_instruction(accounts: &[AccountView], data: &[u8]) -> ProgramResult {
+    let [creator, mint, round, creator_ata, pool_vault, system_program, token_program, _associated_token_program, _extra @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let parsed_data = bytemuck::from_bytes::<RoundData>(&data[..RoundData::LEN]);
+
+    assert!(creator.is_signer(), "Creator must be signer");
+
+    assert!(
+        Mint::from_account_view(mint).unwrap().is_initialized(),
+        "Mint you passed does not exist"
+    );
+
+    assert!(
+        i64::from_le_bytes(parsed_data.end_time) > Clock::get()?.unix_timestamp,
+        "Round must end in the future"
+    );
+
+    assert!(round.is_data_empty(), "Round already exists");
+    assert!(pool_vault.is_data_empty(), "Vault is already initialized");
+
+    let seed = [b"round", creator.address().as_ref()];
+    let (created_round, round_bump) = Address::find_program_address(&seed, &crate::ID);
+
+    assert!(&created_round.eq(round.address()), "Round does not match");
+
+    let bump = round_bump.to_le_bytes();
+
+    let round_seeds = [
+        Seed::from(b"round"),
+        Seed::from(creator.address().as_ref()),
+        Seed::from(&bump),
+    ];
+
+    CreateAccount {
+        from: creator,
+        lamports: Rent::get()?.minimum_balance_unchecked(Round::LEN),
+        owner: &crate::ID,
+        space: Round::LEN as u64,
+        to: round,
+    }
+    .invoke_signed(&[Signer::from(&round_seeds)])?;
+
+    Create {
+        funding_account: creator,
+        system_program,
+        token_program,
+        wallet: round,
+        account: pool_vault,
+        mint,
+    }
+    .invoke()?;
+
+    Transfer {
+        amount: u64::from_le_bytes(parsed_data.pool_amount),
+        authority: creator,
+        from: creator_ata,
+        to: pool_vault,
+    }
+    .invoke()?;
+
+    let mut round_data = round.try_borrow_mut().unwrap();
+    let round_mutable = bytemuck::from_bytes_mut::<Round>(&mut round_data);
+
+    round_mutable.creator = creator.address().as_ref().try_into().unwrap();
+    round_mutable.mint = mint.address().as_ref().try_into().unwrap();
+    round_mutable.pool_vault = pool_vault.address().as_ref().try_into().unwrap();
+    round_mutable.end_time = parsed_data.end_time;
+    round_mutable.bump = bump;
+
+    Ok(())
+}
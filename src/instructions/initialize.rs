@@ -8,9 +8,13 @@ use pinocchio::{
 use pinocchio_associated_token_account::instructions::Create;
 
 use pinocchio_system::instructions::CreateAccount;
-use pinocchio_token::state::Mint;
+use pinocchio_token::{instructions::InitializeMint2, state::Mint};
 
-use crate::state::Fundraiser;
+use crate::instructions::load_config;
+use crate::state::{
+    Fundraiser, PayoutSplit, RewardTier, GOAL_MODE_TOKEN, GOAL_MODE_USD, MAX_ACCEPTED_MINTS,
+    MAX_MILESTONES, MAX_PAYOUT_SPLITS, MAX_REWARD_TIERS, USD_DECIMALS,
+};
 
 #[repr(C, packed)]
 #[derive(Pod, Zeroable, Clone, Copy)]
@@ -19,6 +23,28 @@ pub struct InitData {
     pub max_amount_sendable: [u8; 8],
     pub amount_to_raise: [u8; 8],
     pub duration: [u8; 1],
+    pub goal_mode: [u8; 1],
+    pub max_price_age: [u8; 8],
+    pub max_conf_bps: [u8; 2],
+    pub approver: [u8; 32], // zeroed - milestones are approved by contributor vote
+    pub quorum_bps: [u8; 2],
+    pub threshold_bps: [u8; 2],
+    pub vote_duration: [u8; 8],
+    pub milestones_count: [u8; 1],
+    pub milestones_bps: [[u8; 2]; MAX_MILESTONES],
+    pub vesting_cliff: [u8; 8], // i64, zeroed unless the raise vests linearly to the maker
+    pub vesting_end: [u8; 8],   // i64
+    pub beneficiary: [u8; 32],  // zeroed - the maker receives the funds
+    pub splits_count: [u8; 1],  // zeroed - no split, the beneficiary receives the funds
+    pub splits: [PayoutSplit; MAX_PAYOUT_SPLITS],
+    pub allowlist_root: [u8; 32], // zeroed - anyone can deposit
+    pub attestor: [u8; 32],       // zeroed - deposits need no attestation
+    pub tiers_count: [u8; 1],     // zeroed - no reward tiers
+    pub tiers: [RewardTier; MAX_REWARD_TIERS], // `minted` is ignored
+    pub receipt: [u8; 1],         // 1 - deposits mint fungible receipt tokens
+    pub early_withdraw: [u8; 1],  // 1 - contributors can withdraw before the deadline
+    pub early_withdraw_penalty_bps: [u8; 2],
+    pub pledge_mode: [u8; 1], // 1 - contributors pledge via token delegation instead of depositing
 }
 
 impl InitData {
@@ -31,7 +57,10 @@ impl InitData {
 
 pub fn process_initialize_instruction(accounts: &[AccountView], data: &[u8]) -> ProgramResult {
     // load accounts
-    let [maker, mint_to_raise, fundraiser, vault, system_program, token_program, associated_token_program, rent_sysvar, _extra @ ..] =
+    // any extra accepted mint is passed as a (mint, vault) pair after the fixed accounts
+    // in usd mode the primary price feed comes first and pairs become (mint, vault, price feed)
+    // reward tier mints, one per tier, come next and the receipt mint, if any, is the last account
+    let [maker, mint_to_raise, fundraiser, vault, system_program, token_program, associated_token_program, rent_sysvar, config, remaining @ ..] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -40,9 +69,174 @@ pub fn process_initialize_instruction(accounts: &[AccountView], data: &[u8]) ->
     // check that maker is a signer
     assert!(maker.is_signer(), "Maker should be signer");
 
+    // platform fee terms are frozen for the fundraiser lifetime
+    // no new fundraiser while the program is paused
+    let config_state = load_config(config);
+    assert!(!config_state.is_paused(), "Program paused");
+
+    let (fee_bps, fee_recipient) = (config_state.fee_bps, config_state.fee_recipient);
+
     // cast data to type
     let parsed_data = bytemuck::from_bytes::<InitData>(&data[..InitData::LEN]);
 
+    let goal_mode = parsed_data.goal_mode[0];
+    assert!(
+        goal_mode == GOAL_MODE_TOKEN || goal_mode == GOAL_MODE_USD,
+        "Invalid goal mode"
+    );
+
+    // zero bounds would reject nearly every price and leave the fundraiser unusable
+    if goal_mode == GOAL_MODE_USD {
+        assert!(
+            u64::from_le_bytes(parsed_data.max_price_age) > 0
+                && u16::from_le_bytes(parsed_data.max_conf_bps) > 0,
+            "Price feed bounds must be set"
+        );
+    }
+
+    // milestones, if any, must split the whole raise
+    let milestones_count = parsed_data.milestones_count[0] as usize;
+    assert!(milestones_count <= MAX_MILESTONES, "Too many milestones");
+
+    if milestones_count > 0 {
+        let total_bps: u32 = parsed_data.milestones_bps[..milestones_count]
+            .iter()
+            .map(|bps| u16::from_le_bytes(*bps) as u32)
+            .sum();
+        assert!(total_bps == 10_000, "Milestones must add up to 100%");
+
+        // either a single approver or a contributor vote
+        if parsed_data.approver == [0u8; 32] {
+            let quorum_bps = u16::from_le_bytes(parsed_data.quorum_bps);
+            let threshold_bps = u16::from_le_bytes(parsed_data.threshold_bps);
+
+            assert!(
+                quorum_bps > 0 && quorum_bps <= 10_000,
+                "Invalid vote quorum"
+            );
+            assert!(
+                threshold_bps > 0 && threshold_bps <= 10_000,
+                "Invalid vote threshold"
+            );
+            assert!(
+                u64::from_le_bytes(parsed_data.vote_duration) > 0,
+                "Invalid vote duration"
+            );
+        }
+    }
+
+    // linear vesting replaces the milestone tranches
+    let vesting_end = i64::from_le_bytes(parsed_data.vesting_end);
+    if vesting_end != 0 {
+        assert!(
+            milestones_count == 0,
+            "Vesting and milestones are exclusive"
+        );
+        assert!(
+            i64::from_le_bytes(parsed_data.vesting_cliff) < vesting_end,
+            "Vesting cliff must be before its end"
+        );
+    }
+
+    // payout splits, if any, must share the whole payout
+    let splits_count = parsed_data.splits_count[0] as usize;
+    assert!(splits_count <= MAX_PAYOUT_SPLITS, "Too many payout splits");
+
+    if splits_count > 0 {
+        let total_bps: u32 = parsed_data.splits[..splits_count]
+            .iter()
+            .map(|split| u16::from_le_bytes(split.bps) as u32)
+            .sum();
+        assert!(total_bps == 10_000, "Payout splits must add up to 100%");
+    }
+
+    assert!(
+        u16::from_le_bytes(parsed_data.early_withdraw_penalty_bps) <= 10_000,
+        "Invalid early withdraw penalty"
+    );
+
+    // a minted tier reward can't be taken back from a withdrawn contribution
+    assert!(
+        parsed_data.early_withdraw[0] == 0 || parsed_data.tiers_count[0] == 0,
+        "Early withdraw takes no reward tiers"
+    );
+
+    // pledges are only pulled at settle, so everything checked at deposit time is out
+    if parsed_data.pledge_mode[0] == 1 {
+        assert!(
+            goal_mode == GOAL_MODE_TOKEN
+                && parsed_data.allowlist_root == [0u8; 32]
+                && parsed_data.attestor == [0u8; 32]
+                && parsed_data.tiers_count[0] == 0
+                && parsed_data.receipt[0] == 0
+                && parsed_data.early_withdraw[0] == 0,
+            "Pledge mode takes plain token pledges"
+        );
+    }
+
+    // reward tiers, if any, unlock in order of increasing min amount
+    let tiers_count = parsed_data.tiers_count[0] as usize;
+    assert!(tiers_count <= MAX_REWARD_TIERS, "Too many reward tiers");
+    assert!(
+        parsed_data.tiers[..tiers_count]
+            .windows(2)
+            .all(|pair| u64::from_le_bytes(pair[0].min_amount)
+                < u64::from_le_bytes(pair[1].min_amount)),
+        "Reward tiers must be sorted"
+    );
+    assert!(
+        parsed_data.tiers[..tiers_count]
+            .iter()
+            .all(|tier| u64::from_le_bytes(tier.min_amount) > 0),
+        "Invalid reward tier"
+    );
+
+    let (remaining, receipt_mint) = if parsed_data.receipt[0] == 1 {
+        let (receipt_mint, remaining) = remaining.split_last().expect("Receipt mint missing");
+        (remaining, Some(receipt_mint))
+    } else {
+        (remaining, None)
+    };
+
+    let (remaining, tier_mints) = remaining.split_at(
+        remaining
+            .len()
+            .checked_sub(tiers_count)
+            .expect("Tier mint missing"),
+    );
+
+    let (primary_price_feed, extra_mints) = if goal_mode == GOAL_MODE_USD {
+        let (price_feed, extra_mints) = remaining.split_first().expect("Price feed missing");
+        (Some(price_feed), extra_mints)
+    } else {
+        (None, remaining)
+    };
+    let stride = if goal_mode == GOAL_MODE_USD { 3 } else { 2 };
+
+    // in token mode every extra mint pair comes with its own goal (u64) appended after InitData
+    // in usd mode the headline usd goal is the only one
+    let extra_goals = &data[InitData::LEN..];
+    assert!(
+        extra_mints.len() % stride == 0,
+        "Extra mints must come in pairs"
+    );
+
+    let mints_count = 1 + extra_mints.len() / stride;
+    assert!(mints_count <= MAX_ACCEPTED_MINTS, "Too many accepted mints");
+    assert!(
+        parsed_data.pledge_mode[0] == 0 || mints_count == 1,
+        "Pledge mode takes a single mint"
+    );
+    assert!(
+        extra_goals.len()
+            == if goal_mode == GOAL_MODE_USD {
+                0
+            } else {
+                (mints_count - 1) * 8
+            },
+        "Invalid goals for accepted mints"
+    );
+
     // constraints
     // check that mint exists [similar to mut in ancor]
     let mint_as_state_account = Mint::from_account_view(mint_to_raise).unwrap();
@@ -60,7 +254,7 @@ pub fn process_initialize_instruction(accounts: &[AccountView], data: &[u8]) ->
     assert!(vault.is_data_empty(), "Vault is already initialized");
 
     let rent = Rent::get()?;
-    let minimum_balance = rent.minimum_balance_unchecked(Fundraiser::LEN);
+    let minimum_balance = rent.minimum_balance_unchecked(Fundraiser::space(mints_count));
 
     let seed = [b"fundraiser", maker.address().as_ref()];
     let (created_fundraiser, fundraiser_bump) = Address::find_program_address(&seed, &crate::ID);
@@ -87,7 +281,7 @@ pub fn process_initialize_instruction(accounts: &[AccountView], data: &[u8]) ->
         from: maker,
         lamports: minimum_balance,
         owner: &crate::ID,
-        space: Fundraiser::LEN as u64,
+        space: Fundraiser::space(mints_count) as u64,
         to: fundraiser,
     }
     .invoke_signed(&[Signer::from(&pda_seeds)])?;
@@ -97,21 +291,133 @@ pub fn process_initialize_instruction(accounts: &[AccountView], data: &[u8]) ->
     // Create ATA for Fundraiser - we can decide to move this to client instead 👀 👀
     Create {
         funding_account: maker,
-        system_program: system_program,
-        token_program: token_program,
+        system_program,
+        token_program,
         wallet: fundraiser,
         account: vault,
         mint: mint_to_raise,
     }
     .invoke()?;
 
+    for pair in extra_mints.chunks_exact(stride) {
+        let (extra_mint, extra_vault) = (&pair[0], &pair[1]);
+
+        let extra_mint_as_state_account = Mint::from_account_view(extra_mint).unwrap();
+        assert!(
+            extra_mint_as_state_account.is_initialized(),
+            "Mint you passed does not exist"
+        );
+        // token mode adds raw amounts of every mint into one goal, so they must share decimals
+        assert!(
+            goal_mode != GOAL_MODE_TOKEN
+                || extra_mint_as_state_account.decimals() == mint_as_state_account.decimals(),
+            "Accepted mints decimals differ"
+        );
+        assert!(extra_vault.is_data_empty(), "Vault is already initialized");
+        assert!(
+            extra_mint.address().ne(mint_to_raise.address()),
+            "Mint accepted twice"
+        );
+
+        Create {
+            funding_account: maker,
+            system_program,
+            token_program,
+            wallet: fundraiser,
+            account: extra_vault,
+            mint: extra_mint,
+        }
+        .invoke()?;
+    }
+
+    // receipt mints, the fundraiser mints one token of a tier per contributor reaching it
+    for (index, tier_mint) in tier_mints.iter().enumerate() {
+        let tier_index = [index as u8];
+        let (created_tier_mint, tier_mint_bump) = Address::find_program_address(
+            &[b"tier", fundraiser.address().as_ref(), &tier_index],
+            &crate::ID,
+        );
+
+        assert!(
+            &created_tier_mint.eq(tier_mint.address()),
+            "Tier mint does not match"
+        );
+
+        let tier_mint_bump = [tier_mint_bump];
+        let tier_mint_seeds = [
+            Seed::from(b"tier"),
+            Seed::from(fundraiser.address().as_ref()),
+            Seed::from(&tier_index),
+            Seed::from(&tier_mint_bump),
+        ];
+
+        CreateAccount {
+            from: maker,
+            lamports: rent.minimum_balance_unchecked(Mint::LEN),
+            owner: token_program.address(),
+            space: Mint::LEN as u64,
+            to: tier_mint,
+        }
+        .invoke_signed(&[Signer::from(&tier_mint_seeds)])?;
+
+        InitializeMint2 {
+            mint: tier_mint,
+            decimals: 0,
+            mint_authority: fundraiser.address(),
+            freeze_authority: None,
+        }
+        .invoke()?;
+    }
+
+    // fungible receipts share the decimals of the contributor amount they mirror, the fundraiser
+    // keeps them frozen to their holder
+    if let Some(receipt_mint) = receipt_mint {
+        let (created_receipt_mint, receipt_mint_bump) =
+            Address::find_program_address(&[b"receipt", fundraiser.address().as_ref()], &crate::ID);
+
+        assert!(
+            &created_receipt_mint.eq(receipt_mint.address()),
+            "Receipt mint does not match"
+        );
+
+        let receipt_mint_bump = [receipt_mint_bump];
+        let receipt_mint_seeds = [
+            Seed::from(b"receipt"),
+            Seed::from(fundraiser.address().as_ref()),
+            Seed::from(&receipt_mint_bump),
+        ];
+
+        CreateAccount {
+            from: maker,
+            lamports: rent.minimum_balance_unchecked(Mint::LEN),
+            owner: token_program.address(),
+            space: Mint::LEN as u64,
+            to: receipt_mint,
+        }
+        .invoke_signed(&[Signer::from(&receipt_mint_seeds)])?;
+
+        InitializeMint2 {
+            mint: receipt_mint,
+            decimals: if goal_mode == GOAL_MODE_USD {
+                USD_DECIMALS as u8
+            } else {
+                mint_as_state_account.decimals()
+            },
+            mint_authority: fundraiser.address(),
+            freeze_authority: Some(fundraiser.address()),
+        }
+        .invoke()?;
+    }
+
     // log!("got here 👀");
     // write to the created account
     let mut mut_borrow = fundraiser.try_borrow_mut().unwrap();
 
-    let fundraiser_mutable = bytemuck::from_bytes_mut::<Fundraiser>(&mut mut_borrow);
+    let fundraiser_mutable =
+        bytemuck::from_bytes_mut::<Fundraiser>(&mut mut_borrow[..Fundraiser::LEN]);
 
     fundraiser_mutable.maker = maker.address().as_ref().try_into().unwrap();
+    fundraiser_mutable.creator = fundraiser_mutable.maker;
     fundraiser_mutable.mint_to_raise = mint_to_raise.address().as_ref().try_into().unwrap();
     fundraiser_mutable.amount_to_raise = parsed_data.amount_to_raise;
     fundraiser_mutable.current_amount = 0u64.to_le_bytes();
@@ -119,6 +425,90 @@ pub fn process_initialize_instruction(accounts: &[AccountView], data: &[u8]) ->
     fundraiser_mutable.duration = parsed_data.duration;
     fundraiser_mutable.bump = fundraiser_bump.to_le_bytes();
     fundraiser_mutable.vault = vault.address().as_ref().try_into().unwrap();
+    fundraiser_mutable.accepted_mints_count = (mints_count as u8).to_le_bytes();
+    fundraiser_mutable.goal_mode = parsed_data.goal_mode;
+    fundraiser_mutable.max_price_age = parsed_data.max_price_age;
+    fundraiser_mutable.max_conf_bps = parsed_data.max_conf_bps;
+    fundraiser_mutable.approver = parsed_data.approver;
+    fundraiser_mutable.quorum_bps = parsed_data.quorum_bps;
+    fundraiser_mutable.threshold_bps = parsed_data.threshold_bps;
+    fundraiser_mutable.vote_duration = parsed_data.vote_duration;
+    fundraiser_mutable.milestones_count = parsed_data.milestones_count;
+    fundraiser_mutable.vesting_cliff = parsed_data.vesting_cliff;
+    fundraiser_mutable.vesting_end = parsed_data.vesting_end;
+    fundraiser_mutable.beneficiary = if parsed_data.beneficiary == [0u8; 32] {
+        fundraiser_mutable.maker
+    } else {
+        parsed_data.beneficiary
+    };
+    fundraiser_mutable.splits_count = parsed_data.splits_count;
+    fundraiser_mutable.splits = parsed_data.splits;
+    fundraiser_mutable.allowlist_root = parsed_data.allowlist_root;
+    fundraiser_mutable.attestor = parsed_data.attestor;
+    fundraiser_mutable.tiers_count = parsed_data.tiers_count;
+    fundraiser_mutable.early_withdraw = parsed_data.early_withdraw;
+    fundraiser_mutable.early_withdraw_penalty_bps = parsed_data.early_withdraw_penalty_bps;
+    fundraiser_mutable.pledge_mode = parsed_data.pledge_mode;
+    if let Some(receipt_mint) = receipt_mint {
+        fundraiser_mutable.receipt_mint = receipt_mint.address().as_ref().try_into().unwrap();
+    }
+    fundraiser_mutable.fee_bps = fee_bps;
+    fundraiser_mutable.fee_recipient = fee_recipient;
+
+    for (milestone, bps) in fundraiser_mutable
+        .milestones
+        .iter_mut()
+        .zip(parsed_data.milestones_bps[..milestones_count].iter())
+    {
+        milestone.bps = *bps;
+    }
+
+    for (tier, tier_data) in fundraiser_mutable
+        .tiers
+        .iter_mut()
+        .zip(parsed_data.tiers[..tiers_count].iter())
+    {
+        *tier = RewardTier {
+            minted: [0u8; 4],
+            ..*tier_data
+        };
+    }
+
+    // fill the accepted mints section - entry 0 is always the primary mint
+    // per mint goals are in token units, zeroed in usd mode where the headline usd goal is the
+    // only one
+    let accepted_mints = Fundraiser::accepted_mints_mut(&mut mut_borrow);
+    let mut total_to_raise = u64::from_le_bytes(parsed_data.amount_to_raise);
+
+    accepted_mints[0].mint = mint_to_raise.address().as_ref().try_into().unwrap();
+    accepted_mints[0].vault = vault.address().as_ref().try_into().unwrap();
+    accepted_mints[0].decimals = [mint_as_state_account.decimals()];
+    if let Some(price_feed) = primary_price_feed {
+        accepted_mints[0].price_feed = price_feed.address().as_ref().try_into().unwrap();
+    } else {
+        accepted_mints[0].amount_to_raise = parsed_data.amount_to_raise;
+    }
+
+    for (index, pair) in extra_mints.chunks_exact(stride).enumerate() {
+        let accepted = &mut accepted_mints[index + 1];
+        accepted.mint = pair[0].address().as_ref().try_into().unwrap();
+        accepted.vault = pair[1].address().as_ref().try_into().unwrap();
+        accepted.decimals = [Mint::from_account_view(&pair[0]).unwrap().decimals()];
+        if goal_mode == GOAL_MODE_USD {
+            accepted.price_feed = pair[2].address().as_ref().try_into().unwrap();
+        } else {
+            let goal: [u8; 8] = extra_goals[index * 8..(index + 1) * 8].try_into().unwrap();
+            total_to_raise += u64::from_le_bytes(goal);
+            accepted.amount_to_raise = goal;
+        }
+    }
+
+    // in usd mode the headline goal is the usd amount
+    if goal_mode == GOAL_MODE_TOKEN {
+        let fundraiser_mutable =
+            bytemuck::from_bytes_mut::<Fundraiser>(&mut mut_borrow[..Fundraiser::LEN]);
+        fundraiser_mutable.amount_to_raise = total_to_raise.to_le_bytes();
+    }
 
     Ok(())
 }
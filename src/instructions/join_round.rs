@@ -0,0 +1,84 @@
+use pinocchio::{
+    error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    AccountView, ProgramResult,
+};
+
+use crate::instructions::check_maker;
+use crate::state::{Fundraiser, Round, MAX_ROUND_PROJECTS};
+
+// maker enrolls their fundraiser in a quadratic funding round before it receives any deposit
+// the round creator co-signs, so only projects they accept share the pool
+// accounts: [maker, round creator, fundraiser, round, ...multisig signers]
+pub fn process_join_round_instruction(accounts: &[AccountView]) -> ProgramResult {
+    let [maker, round_creator, fundraiser, round, signers @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    assert!(round_creator.is_signer(), "Round creator must be signer");
+
+    assert!(
+        fundraiser.owned_by(&crate::ID),
+        "Invalid Fundraiser Account"
+    );
+    assert!(round.owned_by(&crate::ID), "Invalid Round Account");
+
+    let mut fundraiser_data = fundraiser.try_borrow_mut().unwrap();
+    let fundraiser_mutable =
+        bytemuck::from_bytes_mut::<Fundraiser>(&mut fundraiser_data[..Fundraiser::LEN]);
+
+    check_maker(maker, &fundraiser_mutable.maker, signers);
+    assert!(
+        !fundraiser_mutable.has_round(),
+        "Fundraiser already in a round"
+    );
+    assert!(
+        !fundraiser_mutable.is_usd_mode(),
+        "Usd mode fundraisers can not join a round"
+    );
+    // round weights come from deposits, pledges could default after the pool is split
+    assert!(
+        !fundraiser_mutable.is_pledge_mode(),
+        "Pledge mode fundraisers can not join a round"
+    );
+
+    // every contribution has to be tracked by the round
+    assert!(
+        u64::from_le_bytes(fundraiser_mutable.current_amount) == 0,
+        "Fundraiser already received deposits"
+    );
+
+    let mut round_data = round.try_borrow_mut().unwrap();
+    let round_mutable = bytemuck::from_bytes_mut::<Round>(&mut round_data);
+
+    assert!(
+        round_creator.address().as_ref() == round_mutable.creator.as_ref(),
+        "Round creator does not match"
+    );
+    assert!(
+        i64::from_le_bytes(round_mutable.end_time) > Clock::get()?.unix_timestamp,
+        "Round closed"
+    );
+    // the round share must be able to land before the deadline decides the raise
+    assert!(
+        i64::from_le_bytes(round_mutable.end_time) as u64 <= fundraiser_mutable.end_time(),
+        "Round ends after the fundraiser"
+    );
+    assert!(
+        fundraiser_mutable.mint_to_raise == round_mutable.mint,
+        "mint do not match"
+    );
+
+    let projects_count = round_mutable.projects_count[0] as usize;
+    assert!(projects_count < MAX_ROUND_PROJECTS, "Round is full");
+
+    let project = &mut round_mutable.projects[projects_count];
+    project.fundraiser = fundraiser.address().as_ref().try_into().unwrap();
+    project.vault = fundraiser_mutable.vault;
+    project.sum_sqrt = 0u64.to_le_bytes();
+
+    round_mutable.projects_count = [projects_count as u8 + 1];
+    fundraiser_mutable.round = round.address().as_ref().try_into().unwrap();
+
+    Ok(())
+}
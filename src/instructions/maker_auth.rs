@@ -0,0 +1,49 @@
+use pinocchio::{AccountView, Address};
+
+use crate::state::Multisig;
+
+// maker signed, or - when the maker is a multisig account - enough of its members signed as the
+// leading accounts of `rest`. returns what follows those signers
+pub fn check_maker<'a>(
+    maker: &AccountView,
+    fundraiser_maker: &[u8; 32],
+    rest: &'a [AccountView],
+) -> &'a [AccountView] {
+    assert!(
+        maker
+            .address()
+            .eq(&Address::new_from_array(*fundraiser_maker)),
+        "Maker does not match"
+    );
+
+    if !maker.owned_by(&crate::ID) {
+        assert!(maker.is_signer(), "Maker should be signer");
+        return rest;
+    }
+
+    let signers_len = rest
+        .iter()
+        .take_while(|account| account.is_signer())
+        .count();
+    let (signers, rest) = rest.split_at(signers_len);
+
+    let multisig_data = maker.try_borrow().unwrap();
+    let multisig = bytemuck::from_bytes::<Multisig>(&multisig_data);
+
+    let approvals = multisig
+        .signers()
+        .iter()
+        .filter(|member| {
+            signers
+                .iter()
+                .any(|signer| signer.address().as_ref() == member.as_ref())
+        })
+        .count();
+
+    assert!(
+        approvals >= multisig.threshold[0] as usize,
+        "Not enough multisig signers"
+    );
+
+    rest
+}
@@ -1,15 +1,118 @@
+pub mod accept_maker_transfer;
+pub mod approve_milestone;
+pub mod attestation;
+pub mod cast_vote;
+pub mod checks;
+pub mod claim;
+pub mod claim_remainder;
 pub mod contribute;
+pub mod distribute_matching;
+pub mod finalize_abort;
+pub mod finalize_matching;
+pub mod fund_matching;
+pub mod init_config;
+pub mod init_denylist;
+pub mod init_multisig;
+pub mod init_round;
 pub mod initialize;
+pub mod join_round;
+pub mod maker_auth;
+pub mod pause;
+pub mod payout;
+pub mod pledge;
+pub mod propose_abort;
+pub mod propose_maker_transfer;
+pub mod receipt;
+pub mod release_tranche;
+pub mod resume;
+pub mod set_beneficiary;
+pub mod set_paused;
+pub mod settle;
+pub mod submit_milestone;
+pub mod tally_milestone;
+pub mod transfer_contribution;
+pub mod update_config;
+pub mod update_denylist;
+pub mod vote_abort;
+pub mod withdraw;
+pub mod withdraw_contribution;
 
+pub use accept_maker_transfer::*;
+pub use approve_milestone::*;
+pub use attestation::*;
+pub use cast_vote::*;
+pub use checks::*;
+pub use claim::*;
+pub use claim_remainder::*;
 pub use contribute::*;
+pub use distribute_matching::*;
+pub use finalize_abort::*;
+pub use finalize_matching::*;
+pub use fund_matching::*;
+pub use init_config::*;
+pub use init_denylist::*;
+pub use init_multisig::*;
+pub use init_round::*;
 pub use initialize::*;
+pub use join_round::*;
+pub use maker_auth::*;
+pub use pause::*;
+pub use payout::*;
+pub use pledge::*;
+pub use propose_abort::*;
+pub use propose_maker_transfer::*;
+pub use receipt::*;
+pub use release_tranche::*;
+pub use resume::*;
+pub use set_beneficiary::*;
+pub use set_paused::*;
+pub use settle::*;
+pub use submit_milestone::*;
+pub use tally_milestone::*;
+pub use transfer_contribution::*;
+pub use update_config::*;
+pub use update_denylist::*;
+pub use vote_abort::*;
+pub use withdraw::*;
+pub use withdraw_contribution::*;
+
 use pinocchio::error::ProgramError;
 
 pub enum FundraiserInstruction {
     Initialize = 0,
     Deposit = 1,
-    // Cancel = 2,
-    // MakeV2 = 3,
+    FundMatching = 2,
+    FinalizeMatching = 3,
+    InitRound = 4,
+    JoinRound = 5,
+    DistributeMatching = 6,
+    Claim = 7,
+    SubmitMilestone = 8,
+    ApproveMilestone = 9,
+    ReleaseTranche = 10,
+    CastVote = 11,
+    TallyMilestone = 12,
+    ProposeAbort = 13,
+    VoteAbort = 14,
+    FinalizeAbort = 15,
+    ClaimRemainder = 16,
+    Withdraw = 17,
+    SetBeneficiary = 18,
+    InitConfig = 19,
+    UpdateConfig = 20,
+    SetPaused = 21,
+    Pause = 22,
+    Resume = 23,
+    ProposeMakerTransfer = 24,
+    AcceptMakerTransfer = 25,
+    InitMultisig = 26,
+    InitDenylist = 27,
+    UpdateDenylist = 28,
+    TransferContribution = 29,
+    WithdrawContribution = 30,
+    Pledge = 31,
+    Settle = 32,
+    DepositFor = 33,
 }
 
 impl TryFrom<&u8> for FundraiserInstruction {
@@ -19,8 +122,38 @@ impl TryFrom<&u8> for FundraiserInstruction {
         match value {
             0 => Ok(FundraiserInstruction::Initialize),
             1 => Ok(FundraiserInstruction::Deposit),
-            // 2 => Ok(FundraiserInstruction::Cancel),
-            // 3 => Ok(FundraiserInstruction::MakeV2),
+            2 => Ok(FundraiserInstruction::FundMatching),
+            3 => Ok(FundraiserInstruction::FinalizeMatching),
+            4 => Ok(FundraiserInstruction::InitRound),
+            5 => Ok(FundraiserInstruction::JoinRound),
+            6 => Ok(FundraiserInstruction::DistributeMatching),
+            7 => Ok(FundraiserInstruction::Claim),
+            8 => Ok(FundraiserInstruction::SubmitMilestone),
+            9 => Ok(FundraiserInstruction::ApproveMilestone),
+            10 => Ok(FundraiserInstruction::ReleaseTranche),
+            11 => Ok(FundraiserInstruction::CastVote),
+            12 => Ok(FundraiserInstruction::TallyMilestone),
+            13 => Ok(FundraiserInstruction::ProposeAbort),
+            14 => Ok(FundraiserInstruction::VoteAbort),
+            15 => Ok(FundraiserInstruction::FinalizeAbort),
+            16 => Ok(FundraiserInstruction::ClaimRemainder),
+            17 => Ok(FundraiserInstruction::Withdraw),
+            18 => Ok(FundraiserInstruction::SetBeneficiary),
+            19 => Ok(FundraiserInstruction::InitConfig),
+            20 => Ok(FundraiserInstruction::UpdateConfig),
+            21 => Ok(FundraiserInstruction::SetPaused),
+            22 => Ok(FundraiserInstruction::Pause),
+            23 => Ok(FundraiserInstruction::Resume),
+            24 => Ok(FundraiserInstruction::ProposeMakerTransfer),
+            25 => Ok(FundraiserInstruction::AcceptMakerTransfer),
+            26 => Ok(FundraiserInstruction::InitMultisig),
+            27 => Ok(FundraiserInstruction::InitDenylist),
+            28 => Ok(FundraiserInstruction::UpdateDenylist),
+            29 => Ok(FundraiserInstruction::TransferContribution),
+            30 => Ok(FundraiserInstruction::WithdrawContribution),
+            31 => Ok(FundraiserInstruction::Pledge),
+            32 => Ok(FundraiserInstruction::Settle),
+            33 => Ok(FundraiserInstruction::DepositFor),
             _ => Err(ProgramError::InvalidInstructionData),
         }
     }
@@ -0,0 +1,39 @@
+use pinocchio::{
+    error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    AccountView, ProgramResult,
+};
+
+use crate::instructions::check_maker;
+use crate::state::Fundraiser;
+
+// maker stops deposits on a running fundraiser until it is resumed
+// accounts: [maker, fundraiser, ...multisig signers]
+pub fn process_pause_instruction(accounts: &[AccountView]) -> ProgramResult {
+    let [maker, fundraiser, signers @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    assert!(
+        fundraiser.owned_by(&crate::ID),
+        "Invalid Fundraiser Account"
+    );
+
+    let mut fundraiser_data = fundraiser.try_borrow_mut().unwrap();
+    let fundraiser_mutable =
+        bytemuck::from_bytes_mut::<Fundraiser>(&mut fundraiser_data[..Fundraiser::LEN]);
+
+    check_maker(maker, &fundraiser_mutable.maker, signers);
+    assert!(!fundraiser_mutable.is_paused(), "Fundraiser already paused");
+
+    let current_time_unix = Clock::get()?.unix_timestamp;
+
+    assert!(
+        fundraiser_mutable.end_time() > current_time_unix as u64,
+        "Fundraising closed"
+    );
+
+    fundraiser_mutable.paused_at = current_time_unix.to_le_bytes();
+
+    Ok(())
+}
@@ -0,0 +1,167 @@
+use bytemuck::Zeroable;
+use pinocchio::{
+    cpi::{Seed, Signer},
+    AccountView, Address, ProgramResult,
+};
+use pinocchio_token::{instructions::Transfer, state::TokenAccount};
+
+use crate::state::{AcceptedMint, Fundraiser, MAX_ACCEPTED_MINTS};
+
+// pays `bps` of every accepted mint raised amount out of its vault, signed by the fundraiser pda
+// payout_accounts: (vault, [fee token account], ...recipient token accounts) per accepted mint,
+// in table order
+pub fn pay_out(
+    fundraiser: &AccountView,
+    payout_accounts: &[AccountView],
+    bps: u16,
+) -> ProgramResult {
+    // copy what we need so the fundraiser isn't borrowed while it signs
+    let (fundraiser_state, accepted_mints, mints_count) = {
+        let fundraiser_data = fundraiser.try_borrow().unwrap();
+        let fundraiser_state =
+            *bytemuck::from_bytes::<Fundraiser>(&fundraiser_data[..Fundraiser::LEN]);
+
+        let mut accepted_mints = [AcceptedMint::zeroed(); MAX_ACCEPTED_MINTS];
+        let table = Fundraiser::accepted_mints(&fundraiser_data);
+        accepted_mints[..table.len()].copy_from_slice(table);
+
+        (fundraiser_state, accepted_mints, table.len())
+    };
+
+    assert!(!fundraiser_state.is_aborted(), "Fundraiser aborted");
+    assert!(!fundraiser_state.is_paused(), "Fundraiser paused");
+    assert!(!fundraiser_state.awaits_round(), "Round not distributed");
+    assert!(
+        fundraiser_state.pledged_amount == [0u8; 8],
+        "Pledges not settled"
+    );
+
+    let chunk_len = 1 + fundraiser_state.payout_destinations_count();
+    assert!(
+        payout_accounts.len() == mints_count * chunk_len,
+        "Every accepted mint vault must be passed"
+    );
+
+    for (accepted_mint, accounts) in accepted_mints[..mints_count]
+        .iter()
+        .zip(payout_accounts.chunks_exact(chunk_len))
+    {
+        let (vault, destinations) = accounts.split_first().unwrap();
+
+        assert!(
+            vault
+                .address()
+                .eq(&Address::new_from_array(accepted_mint.vault)),
+            "vault do not match"
+        );
+
+        let amount = (u64::from_le_bytes(accepted_mint.current_amount) as u128 * bps as u128
+            / 10_000) as u64;
+
+        split_transfer(&fundraiser_state, fundraiser, vault, destinations, amount)?;
+    }
+
+    Ok(())
+}
+
+// sends `amount` out of `vault` minus the platform fee to the beneficiary, or across the payout
+// splits. destinations: the fee token account if the fundraiser has a fee, then one token account
+// per split in table order, or the beneficiary one
+pub fn split_transfer(
+    fundraiser_state: &Fundraiser,
+    fundraiser: &AccountView,
+    vault: &AccountView,
+    destinations: &[AccountView],
+    amount: u64,
+) -> ProgramResult {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    let bump = fundraiser_state.bump;
+    let fundraiser_seeds = [
+        Seed::from(b"fundraiser"),
+        Seed::from(fundraiser_state.creator.as_ref()),
+        Seed::from(&bump),
+    ];
+
+    let (amount, destinations) = if fundraiser_state.has_fee() {
+        let (fee_destination, destinations) = destinations.split_first().unwrap();
+
+        assert!(
+            TokenAccount::from_account_view(fee_destination)?
+                .owner()
+                .eq(&Address::new_from_array(fundraiser_state.fee_recipient)),
+            "Destination not owned by fee recipient"
+        );
+
+        let fee =
+            (amount as u128 * u16::from_le_bytes(fundraiser_state.fee_bps) as u128 / 10_000) as u64;
+
+        if fee > 0 {
+            Transfer {
+                amount: fee,
+                authority: fundraiser,
+                from: vault,
+                to: fee_destination,
+            }
+            .invoke_signed(&[Signer::from(&fundraiser_seeds)])?;
+        }
+
+        (amount - fee, destinations)
+    } else {
+        (amount, destinations)
+    };
+
+    let splits = fundraiser_state.payout_splits();
+
+    if splits.is_empty() {
+        assert!(
+            TokenAccount::from_account_view(&destinations[0])?
+                .owner()
+                .eq(&Address::new_from_array(fundraiser_state.beneficiary)),
+            "Destination not owned by beneficiary"
+        );
+
+        return Transfer {
+            amount,
+            authority: fundraiser,
+            from: vault,
+            to: &destinations[0],
+        }
+        .invoke_signed(&[Signer::from(&fundraiser_seeds)]);
+    }
+
+    let mut sent = 0u64;
+
+    for (index, (split, destination)) in splits.iter().zip(destinations.iter()).enumerate() {
+        assert!(
+            TokenAccount::from_account_view(destination)?
+                .owner()
+                .eq(&Address::new_from_array(split.recipient)),
+            "Destination not owned by recipient"
+        );
+
+        // last recipient takes the rounding dust
+        let share = if index == splits.len() - 1 {
+            amount - sent
+        } else {
+            (amount as u128 * u16::from_le_bytes(split.bps) as u128 / 10_000) as u64
+        };
+        sent += share;
+
+        if share == 0 {
+            continue;
+        }
+
+        Transfer {
+            amount: share,
+            authority: fundraiser,
+            from: vault,
+            to: destination,
+        }
+        .invoke_signed(&[Signer::from(&fundraiser_seeds)])?;
+    }
+
+    Ok(())
+}
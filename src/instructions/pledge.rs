@@ -0,0 +1,138 @@
+use pinocchio::{
+    cpi::{Seed, Signer},
+    error::ProgramError,
+    sysvars::{clock::Clock, rent::Rent, Sysvar},
+    AccountView, Address, ProgramResult,
+};
+use pinocchio_system::instructions::CreateAccount;
+use pinocchio_token::{instructions::Approve, state::TokenAccount};
+
+use crate::instructions::{assert_not_denylisted, check_contributor, load_config};
+use crate::state::{Contributor, Fundraiser};
+
+// contributor pledges `amount` more without moving funds - the fundraiser is approved as delegate
+// of the contributor token account for the whole pledge, pulled by settle once the raise succeeds
+// data: [amount (u64)]
+// accounts: [contributor, fundraiser, contributor state, contributor ata, system program,
+//            token program, config, denylist (only once created)]
+pub fn process_pledge_instruction(accounts: &[AccountView], data: &[u8]) -> ProgramResult {
+    let [contributor, fundraiser, contributor_state_account, contributor_ata, _system_program, _token_program, config, rest @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let amount = u64::from_le_bytes(
+        data.get(..8)
+            .ok_or(ProgramError::InvalidInstructionData)?
+            .try_into()
+            .unwrap(),
+    );
+
+    assert!(contributor.is_signer(), "Contributor must be signer");
+    assert!(
+        fundraiser.owned_by(&crate::ID),
+        "Invalid Fundraiser Account"
+    );
+    assert!(amount > 0, "Nothing to pledge");
+
+    // no pledge while the program is paused, nor from a denylisted wallet
+    let config_state = load_config(config);
+    assert!(!config_state.is_paused(), "Program paused");
+
+    if config_state.has_denylist() {
+        let denylist = rest.first().expect("Denylist missing");
+        assert_not_denylisted(denylist, &[contributor.address()], "Contributor denylisted");
+    }
+
+    let contributor_bump = check_contributor(contributor_state_account, fundraiser, contributor);
+
+    // if contributor state doesn't exist, create it
+    if contributor_state_account.is_data_empty() {
+        let bump = contributor_bump.to_le_bytes();
+        let contributor_seeds = [
+            Seed::from(b"contributor"),
+            Seed::from(fundraiser.address().as_ref()),
+            Seed::from(contributor.address().as_ref()),
+            Seed::from(&bump),
+        ];
+
+        CreateAccount {
+            from: contributor,
+            lamports: Rent::get()?.minimum_balance_unchecked(Contributor::LEN),
+            owner: &crate::ID,
+            space: Contributor::LEN as u64,
+            to: contributor_state_account,
+        }
+        .invoke_signed(&[Signer::from(&contributor_seeds)])?;
+    }
+
+    let total_pledged = {
+        let mut fundraiser_data = fundraiser.try_borrow_mut().unwrap();
+        let fundraiser_mutable =
+            bytemuck::from_bytes_mut::<Fundraiser>(&mut fundraiser_data[..Fundraiser::LEN]);
+
+        assert!(
+            fundraiser_mutable.is_pledge_mode(),
+            "Fundraiser takes deposits"
+        );
+        assert!(
+            fundraiser_mutable.end_time() > Clock::get()?.unix_timestamp as u64,
+            "Fundraising closed"
+        );
+        assert!(!fundraiser_mutable.is_paused(), "Fundraiser paused");
+
+        {
+            let contributor_ata_state = TokenAccount::from_account_view(contributor_ata)?;
+            assert!(
+                contributor_ata_state.mint().as_ref() == fundraiser_mutable.mint_to_raise.as_ref(),
+                "mint do not match"
+            );
+            assert!(
+                contributor_ata_state.owner().eq(contributor.address()),
+                "Token account not owned by contributor"
+            );
+        }
+
+        let mut contributor_state_data = contributor_state_account.try_borrow_mut().unwrap();
+        let contributor_mutable =
+            bytemuck::from_bytes_mut::<Contributor>(&mut contributor_state_data);
+
+        // a single pledge account per contributor, the delegation covers the running total
+        if contributor_mutable.pledged != [0u8; 8] {
+            assert!(
+                contributor_ata
+                    .address()
+                    .eq(&Address::new_from_array(contributor_mutable.pledge_account)),
+                "Pledge account does not match"
+            );
+        }
+
+        let total_pledged = u64::from_le_bytes(contributor_mutable.pledged) + amount;
+        contributor_mutable.pledged = total_pledged.to_le_bytes();
+        contributor_mutable.pledge_account = contributor_ata.address().as_ref().try_into().unwrap();
+
+        let raised = u64::from_le_bytes(fundraiser_mutable.current_amount);
+        fundraiser_mutable.current_amount = (raised + amount).to_le_bytes();
+
+        let pledged = u64::from_le_bytes(fundraiser_mutable.pledged_amount);
+        fundraiser_mutable.pledged_amount = (pledged + amount).to_le_bytes();
+
+        total_pledged
+    };
+
+    assert!(
+        TokenAccount::from_account_view(contributor_ata)?.amount() >= total_pledged,
+        "insufficient contributor balance"
+    );
+
+    Approve {
+        source: contributor_ata,
+        delegate: fundraiser,
+        authority: contributor,
+        amount: total_pledged,
+    }
+    .invoke()?;
+
+    Ok(())
+}
@@ -0,0 +1,125 @@
+use pinocchio::{
+    cpi::{Seed, Signer},
+    error::ProgramError,
+    sysvars::{clock::Clock, rent::Rent, Sysvar},
+    AccountView, Address, ProgramResult,
+};
+use pinocchio_system::instructions::CreateAccount;
+
+use crate::instructions::check_contributor;
+use crate::state::{
+    Contributor, Fundraiser, Proposal, ABORT_VOTE_DURATION, MAX_ACCEPTED_MINTS, MILESTONE_RELEASED,
+    PROPOSAL_OPEN, PROPOSAL_REJECTED,
+};
+
+// any contributor opens the kill switch vote on a project still releasing milestones
+// a rejected proposal can be proposed again, its votes move to the next nonce
+// accounts: [contributor, fundraiser, contributor state, proposal, system program]
+pub fn process_propose_abort_instruction(accounts: &[AccountView]) -> ProgramResult {
+    let [contributor, fundraiser, contributor_state_account, proposal, _system_program, _extra @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    assert!(contributor.is_signer(), "Contributor must be signer");
+    assert!(
+        fundraiser.owned_by(&crate::ID),
+        "Invalid Fundraiser Account"
+    );
+    assert!(
+        contributor_state_account.owned_by(&crate::ID),
+        "Invalid Contributor Account"
+    );
+
+    check_contributor(contributor_state_account, fundraiser, contributor);
+
+    {
+        let contributor_state_data = contributor_state_account.try_borrow().unwrap();
+        let contributor_state = bytemuck::from_bytes::<Contributor>(&contributor_state_data);
+        assert!(
+            u64::from_le_bytes(contributor_state.amount) > 0,
+            "Only contributors can propose"
+        );
+    }
+
+    let current_time_unix = Clock::get()?.unix_timestamp;
+
+    {
+        let fundraiser_data = fundraiser.try_borrow().unwrap();
+        let fundraiser_state =
+            bytemuck::from_bytes::<Fundraiser>(&fundraiser_data[..Fundraiser::LEN]);
+
+        assert!(
+            fundraiser_state.is_successful(current_time_unix),
+            "Fundraiser not successful"
+        );
+        assert!(
+            fundraiser_state.has_milestones(),
+            "Funds are not released per milestone"
+        );
+        assert!(
+            fundraiser_state.milestones[..fundraiser_state.milestones_count[0] as usize]
+                .iter()
+                .any(|milestone| milestone.status[0] != MILESTONE_RELEASED),
+            "Every milestone released"
+        );
+    }
+
+    // single proposal per fundraiser, only reopened once rejected
+    let seed = [b"proposal", fundraiser.address().as_ref()];
+    let (created_proposal, proposal_bump) = Address::find_program_address(&seed, &crate::ID);
+
+    assert!(
+        &created_proposal.eq(proposal.address()),
+        "Proposal does not match"
+    );
+
+    let bump = proposal_bump.to_le_bytes();
+
+    let nonce = if proposal.is_data_empty() {
+        let proposal_seeds = [
+            Seed::from(b"proposal"),
+            Seed::from(fundraiser.address().as_ref()),
+            Seed::from(&bump),
+        ];
+
+        CreateAccount {
+            from: contributor,
+            lamports: Rent::get()?.minimum_balance_unchecked(Proposal::LEN),
+            owner: &crate::ID,
+            space: Proposal::LEN as u64,
+            to: proposal,
+        }
+        .invoke_signed(&[Signer::from(&proposal_seeds)])?;
+
+        0
+    } else {
+        assert!(proposal.owned_by(&crate::ID), "Invalid Proposal Account");
+
+        let proposal_data = proposal.try_borrow().unwrap();
+        let proposal_state = bytemuck::from_bytes::<Proposal>(&proposal_data);
+        assert!(
+            proposal_state.status[0] == PROPOSAL_REJECTED,
+            "Proposal already exists"
+        );
+
+        u64::from_le_bytes(proposal_state.nonce) + 1
+    };
+
+    let mut proposal_data = proposal.try_borrow_mut().unwrap();
+    let proposal_mutable = bytemuck::from_bytes_mut::<Proposal>(&mut proposal_data);
+
+    proposal_mutable.fundraiser = fundraiser.address().as_ref().try_into().unwrap();
+    proposal_mutable.proposer = contributor.address().as_ref().try_into().unwrap();
+    proposal_mutable.ends_at = (current_time_unix + ABORT_VOTE_DURATION).to_le_bytes();
+    proposal_mutable.abort_weight = 0u64.to_le_bytes();
+    proposal_mutable.keep_weight = 0u64.to_le_bytes();
+    proposal_mutable.total_weight = 0u64.to_le_bytes();
+    proposal_mutable.remaining = [[0u8; 8]; MAX_ACCEPTED_MINTS];
+    proposal_mutable.nonce = nonce.to_le_bytes();
+    proposal_mutable.status = [PROPOSAL_OPEN];
+    proposal_mutable.bump = bump;
+
+    Ok(())
+}
@@ -0,0 +1,27 @@
+use pinocchio::{error::ProgramError, AccountView, ProgramResult};
+
+use crate::instructions::check_maker;
+use crate::state::Fundraiser;
+
+// maker nominates who takes over the fundraiser - nothing changes until they accept
+// accounts: [maker, fundraiser, new maker, ...multisig signers]
+pub fn process_propose_maker_transfer_instruction(accounts: &[AccountView]) -> ProgramResult {
+    let [maker, fundraiser, new_maker, signers @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    assert!(
+        fundraiser.owned_by(&crate::ID),
+        "Invalid Fundraiser Account"
+    );
+
+    let mut fundraiser_data = fundraiser.try_borrow_mut().unwrap();
+    let fundraiser_mutable =
+        bytemuck::from_bytes_mut::<Fundraiser>(&mut fundraiser_data[..Fundraiser::LEN]);
+
+    check_maker(maker, &fundraiser_mutable.maker, signers);
+
+    fundraiser_mutable.pending_maker = new_maker.address().as_ref().try_into().unwrap();
+
+    Ok(())
+}
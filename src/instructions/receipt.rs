@@ -0,0 +1,45 @@
+use pinocchio::{
+    cpi::{Seed, Signer},
+    AccountView, ProgramResult,
+};
+use pinocchio_token::{
+    instructions::{FreezeAccount, ThawAccount},
+    state::TokenAccount,
+};
+
+// receipts sit frozen in their holder account so they can't drift away from the position they
+// mirror - the fundraiser, freeze authority of the receipt mint, thaws them only around the
+// mint, burn or transfer of the instruction moving that position
+
+pub fn thaw_receipts(
+    receipt_account: &AccountView,
+    receipt_mint: &AccountView,
+    fundraiser: &AccountView,
+    fundraiser_seeds: &[Seed],
+) -> ProgramResult {
+    // a receipt account created in this instruction starts out thawed
+    if !TokenAccount::from_account_view(receipt_account)?.is_frozen() {
+        return Ok(());
+    }
+
+    ThawAccount {
+        account: receipt_account,
+        mint: receipt_mint,
+        freeze_authority: fundraiser,
+    }
+    .invoke_signed(&[Signer::from(fundraiser_seeds)])
+}
+
+pub fn freeze_receipts(
+    receipt_account: &AccountView,
+    receipt_mint: &AccountView,
+    fundraiser: &AccountView,
+    fundraiser_seeds: &[Seed],
+) -> ProgramResult {
+    FreezeAccount {
+        account: receipt_account,
+        mint: receipt_mint,
+        freeze_authority: fundraiser,
+    }
+    .invoke_signed(&[Signer::from(fundraiser_seeds)])
+}
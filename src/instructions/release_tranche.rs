@@ -0,0 +1,44 @@
+use pinocchio::{error::ProgramError, AccountView, ProgramResult};
+
+use crate::instructions::{check_maker, pay_out};
+use crate::state::{Fundraiser, MILESTONE_APPROVED, MILESTONE_RELEASED};
+
+// pays the tranche of an approved milestone - data: [milestone index]
+// accounts: [maker, fundraiser, token program, ...multisig signers, ...(vault, ...recipient token accounts) per accepted mint]
+pub fn process_release_tranche_instruction(accounts: &[AccountView], data: &[u8]) -> ProgramResult {
+    let [maker, fundraiser, _token_program, rest @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let index = *data.first().ok_or(ProgramError::InvalidInstructionData)? as usize;
+
+    assert!(
+        fundraiser.owned_by(&crate::ID),
+        "Invalid Fundraiser Account"
+    );
+
+    let (payout_accounts, bps) = {
+        let mut fundraiser_data = fundraiser.try_borrow_mut().unwrap();
+        let fundraiser_mutable =
+            bytemuck::from_bytes_mut::<Fundraiser>(&mut fundraiser_data[..Fundraiser::LEN]);
+
+        let payout_accounts = check_maker(maker, &fundraiser_mutable.maker, rest);
+
+        assert!(
+            index < fundraiser_mutable.milestones_count[0] as usize,
+            "Invalid milestone"
+        );
+
+        let milestone = &mut fundraiser_mutable.milestones[index];
+        assert!(
+            milestone.status[0] == MILESTONE_APPROVED,
+            "Milestone not approved"
+        );
+
+        milestone.status = [MILESTONE_RELEASED];
+
+        (payout_accounts, u16::from_le_bytes(milestone.bps))
+    };
+
+    pay_out(fundraiser, payout_accounts, bps)
+}
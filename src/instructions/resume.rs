@@ -0,0 +1,46 @@
+use pinocchio::{
+    error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    AccountView, ProgramResult,
+};
+
+use crate::instructions::check_maker;
+use crate::state::{Fundraiser, MAX_DEADLINE_EXTENSION};
+
+// maker reopens deposits, optionally pushing the deadline back by the time spent paused
+// up to MAX_DEADLINE_EXTENSION over the life of the fundraiser
+// data: [extend deadline (1) / keep it (0)]
+// accounts: [maker, fundraiser, ...multisig signers]
+pub fn process_resume_instruction(accounts: &[AccountView], data: &[u8]) -> ProgramResult {
+    let [maker, fundraiser, signers @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let extend = *data.first().ok_or(ProgramError::InvalidInstructionData)? == 1;
+
+    assert!(
+        fundraiser.owned_by(&crate::ID),
+        "Invalid Fundraiser Account"
+    );
+
+    let mut fundraiser_data = fundraiser.try_borrow_mut().unwrap();
+    let fundraiser_mutable =
+        bytemuck::from_bytes_mut::<Fundraiser>(&mut fundraiser_data[..Fundraiser::LEN]);
+
+    check_maker(maker, &fundraiser_mutable.maker, signers);
+    assert!(fundraiser_mutable.is_paused(), "Fundraiser not paused");
+
+    if extend {
+        let paused_for =
+            Clock::get()?.unix_timestamp - i64::from_le_bytes(fundraiser_mutable.paused_at);
+        let extension = (u64::from_le_bytes(fundraiser_mutable.deadline_extension)
+            + paused_for as u64)
+            .min(MAX_DEADLINE_EXTENSION);
+
+        fundraiser_mutable.deadline_extension = extension.to_le_bytes();
+    }
+
+    fundraiser_mutable.paused_at = [0u8; 8];
+
+    Ok(())
+}
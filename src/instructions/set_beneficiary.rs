@@ -0,0 +1,32 @@
+use pinocchio::{error::ProgramError, AccountView, Address, ProgramResult};
+
+use crate::state::Fundraiser;
+
+// current beneficiary hands the payouts over to another wallet
+// accounts: [beneficiary, fundraiser, new beneficiary]
+pub fn process_set_beneficiary_instruction(accounts: &[AccountView]) -> ProgramResult {
+    let [beneficiary, fundraiser, new_beneficiary, _extra @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    assert!(beneficiary.is_signer(), "Beneficiary should be signer");
+    assert!(
+        fundraiser.owned_by(&crate::ID),
+        "Invalid Fundraiser Account"
+    );
+
+    let mut fundraiser_data = fundraiser.try_borrow_mut().unwrap();
+    let fundraiser_mutable =
+        bytemuck::from_bytes_mut::<Fundraiser>(&mut fundraiser_data[..Fundraiser::LEN]);
+
+    assert!(
+        beneficiary
+            .address()
+            .eq(&Address::new_from_array(fundraiser_mutable.beneficiary)),
+        "Beneficiary does not match"
+    );
+
+    fundraiser_mutable.beneficiary = new_beneficiary.address().as_ref().try_into().unwrap();
+
+    Ok(())
+}
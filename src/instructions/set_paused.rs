@@ -0,0 +1,32 @@
+use pinocchio::{error::ProgramError, AccountView, Address, ProgramResult};
+
+use crate::instructions::check_config;
+use crate::state::Config;
+
+// admin pauses or resumes new fundraisers and deposits program wide
+// data: [paused (1) / resumed (0)]
+// accounts: [admin, config]
+pub fn process_set_paused_instruction(accounts: &[AccountView], data: &[u8]) -> ProgramResult {
+    let [admin, config, _extra @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let paused = *data.first().ok_or(ProgramError::InvalidInstructionData)? == 1;
+
+    assert!(admin.is_signer(), "Admin must be signer");
+    check_config(config);
+
+    let mut config_data = config.try_borrow_mut().unwrap();
+    let config_mutable = bytemuck::from_bytes_mut::<Config>(&mut config_data);
+
+    assert!(
+        admin
+            .address()
+            .eq(&Address::new_from_array(config_mutable.admin)),
+        "Admin does not match"
+    );
+
+    config_mutable.paused = [paused as u8];
+
+    Ok(())
+}
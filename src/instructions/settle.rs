@@ -0,0 +1,135 @@
+use pinocchio::{
+    cpi::{Seed, Signer},
+    error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    AccountView, Address, ProgramResult,
+};
+use pinocchio_token::{instructions::Transfer, state::TokenAccount};
+
+use crate::instructions::check_contributor;
+use crate::state::{Contributor, Fundraiser};
+
+// anyone pulls a contributor pledge into the vault once the raise succeeded
+// whatever the delegation or balance no longer covers is dropped from the raised amount, so a
+// defaulted pledge still settles (to 0) and can't hold the payout back
+// accounts: [fundraiser, contributor, contributor state, pledge account, vault, token program]
+pub fn process_settle_instruction(accounts: &[AccountView]) -> ProgramResult {
+    let [fundraiser, contributor, contributor_state_account, pledge_account, vault, _token_program, _extra @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    assert!(
+        fundraiser.owned_by(&crate::ID),
+        "Invalid Fundraiser Account"
+    );
+    assert!(
+        contributor_state_account.owned_by(&crate::ID),
+        "Invalid Contributor Account"
+    );
+
+    check_contributor(contributor_state_account, fundraiser, contributor);
+
+    let (pledged, fundraiser_state) = {
+        let mut fundraiser_data = fundraiser.try_borrow_mut().unwrap();
+        let fundraiser_mutable =
+            bytemuck::from_bytes_mut::<Fundraiser>(&mut fundraiser_data[..Fundraiser::LEN]);
+
+        // the goal is checked once, by the first settle - later defaults lowering the raised
+        // amount must not fail the raise and lock what was already settled
+        assert!(
+            fundraiser_mutable.is_successful(Clock::get()?.unix_timestamp),
+            "Fundraiser not successful"
+        );
+        fundraiser_mutable.succeeded = [1];
+
+        let fundraiser_state = *fundraiser_mutable;
+        assert!(
+            vault
+                .address()
+                .eq(&Address::new_from_array(fundraiser_state.vault)),
+            "vault do not match"
+        );
+
+        // clearing the pledge makes settle one shot
+        let mut contributor_state_data = contributor_state_account.try_borrow_mut().unwrap();
+        let contributor_mutable =
+            bytemuck::from_bytes_mut::<Contributor>(&mut contributor_state_data);
+
+        let pledged = u64::from_le_bytes(contributor_mutable.pledged);
+        assert!(pledged > 0, "Nothing to settle");
+        assert!(
+            pledge_account
+                .address()
+                .eq(&Address::new_from_array(contributor_mutable.pledge_account)),
+            "Pledge account does not match"
+        );
+
+        contributor_mutable.pledged = 0u64.to_le_bytes();
+
+        (pledged, fundraiser_state)
+    };
+
+    // the contributor may have spent or revoked part of the pledge since
+    let settled = {
+        let pledge_account_state = TokenAccount::from_account_view(pledge_account)?;
+
+        if pledge_account_state.delegate() == Some(fundraiser.address()) {
+            pledged
+                .min(pledge_account_state.delegated_amount())
+                .min(pledge_account_state.amount())
+        } else {
+            0
+        }
+    };
+
+    if settled > 0 {
+        let bump = fundraiser_state.bump;
+        let fundraiser_seeds = [
+            Seed::from(b"fundraiser"),
+            Seed::from(fundraiser_state.creator.as_ref()),
+            Seed::from(&bump),
+        ];
+
+        Transfer {
+            amount: settled,
+            authority: fundraiser,
+            from: pledge_account,
+            to: vault,
+        }
+        .invoke_signed(&[Signer::from(&fundraiser_seeds)])?;
+    }
+
+    // only settled funds carry refund and vote rights
+    {
+        let mut contributor_state_data = contributor_state_account.try_borrow_mut().unwrap();
+        let contributor_mutable =
+            bytemuck::from_bytes_mut::<Contributor>(&mut contributor_state_data);
+
+        let amount = u64::from_le_bytes(contributor_mutable.amount) + settled;
+        contributor_mutable.amount = amount.to_le_bytes();
+        let mint_amount = u64::from_le_bytes(contributor_mutable.amounts[0]) + settled;
+        contributor_mutable.amounts[0] = mint_amount.to_le_bytes();
+    }
+
+    let mut fundraiser_data = fundraiser.try_borrow_mut().unwrap();
+
+    let accepted_mint = &mut Fundraiser::accepted_mints_mut(&mut fundraiser_data)[0];
+    let raised_for_mint = u64::from_le_bytes(accepted_mint.current_amount);
+    accepted_mint.current_amount = (raised_for_mint + settled).to_le_bytes();
+
+    let fundraiser_mutable =
+        bytemuck::from_bytes_mut::<Fundraiser>(&mut fundraiser_data[..Fundraiser::LEN]);
+
+    let raised = u64::from_le_bytes(fundraiser_mutable.current_amount);
+    fundraiser_mutable.current_amount = (raised - (pledged - settled)).to_le_bytes();
+
+    let outstanding = u64::from_le_bytes(fundraiser_mutable.pledged_amount);
+    fundraiser_mutable.pledged_amount = (outstanding - pledged).to_le_bytes();
+
+    let contributed = u64::from_le_bytes(fundraiser_mutable.contributed_amount);
+    fundraiser_mutable.contributed_amount = (contributed + settled).to_le_bytes();
+
+    Ok(())
+}
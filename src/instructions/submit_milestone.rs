@@ -0,0 +1,60 @@
+use pinocchio::{
+    error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    AccountView, ProgramResult,
+};
+
+use crate::instructions::check_maker;
+use crate::state::{Fundraiser, MILESTONE_PENDING, MILESTONE_SUBMITTED};
+
+// maker marks a milestone as done - data: [milestone index]
+pub fn process_submit_milestone_instruction(
+    accounts: &[AccountView],
+    data: &[u8],
+) -> ProgramResult {
+    let [maker, fundraiser, signers @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let index = *data.first().ok_or(ProgramError::InvalidInstructionData)? as usize;
+
+    assert!(
+        fundraiser.owned_by(&crate::ID),
+        "Invalid Fundraiser Account"
+    );
+
+    let mut fundraiser_data = fundraiser.try_borrow_mut().unwrap();
+    let fundraiser_mutable =
+        bytemuck::from_bytes_mut::<Fundraiser>(&mut fundraiser_data[..Fundraiser::LEN]);
+
+    check_maker(maker, &fundraiser_mutable.maker, signers);
+    let current_time_unix = Clock::get()?.unix_timestamp;
+
+    assert!(
+        fundraiser_mutable.is_successful(current_time_unix),
+        "Fundraiser not successful"
+    );
+    assert!(
+        index < fundraiser_mutable.milestones_count[0] as usize,
+        "Invalid milestone"
+    );
+
+    // milestones go in order
+    assert!(
+        fundraiser_mutable.milestones[..index]
+            .iter()
+            .all(|milestone| milestone.is_settled()),
+        "Previous milestone not settled"
+    );
+
+    let milestone = &mut fundraiser_mutable.milestones[index];
+    assert!(
+        milestone.status[0] == MILESTONE_PENDING,
+        "Milestone already submitted"
+    );
+
+    milestone.status = [MILESTONE_SUBMITTED];
+    milestone.submitted_at = current_time_unix.to_le_bytes();
+
+    Ok(())
+}
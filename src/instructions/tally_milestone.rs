@@ -0,0 +1,78 @@
+use pinocchio::{
+    error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    AccountView, ProgramResult,
+};
+
+use crate::instructions::pay_out;
+use crate::state::{Fundraiser, MILESTONE_FROZEN, MILESTONE_RELEASED, MILESTONE_SUBMITTED};
+
+// permissionless - once voting closed, pays the tranche if quorum and threshold are met,
+// freezes it otherwise. data: [milestone index]
+// accounts: [fundraiser, token program, ...(vault, ...recipient token accounts) per accepted mint]
+pub fn process_tally_milestone_instruction(accounts: &[AccountView], data: &[u8]) -> ProgramResult {
+    let [fundraiser, _token_program, payout_accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let index = *data.first().ok_or(ProgramError::InvalidInstructionData)? as usize;
+
+    assert!(
+        fundraiser.owned_by(&crate::ID),
+        "Invalid Fundraiser Account"
+    );
+
+    let (passed, bps) = {
+        let mut fundraiser_data = fundraiser.try_borrow_mut().unwrap();
+        let fundraiser_mutable =
+            bytemuck::from_bytes_mut::<Fundraiser>(&mut fundraiser_data[..Fundraiser::LEN]);
+
+        assert!(
+            fundraiser_mutable.is_vote_approved(),
+            "Milestones are approved by the approver"
+        );
+        assert!(
+            index < fundraiser_mutable.milestones_count[0] as usize,
+            "Invalid milestone"
+        );
+
+        let total_weight = u64::from_le_bytes(fundraiser_mutable.contributed_amount) as u128;
+        let quorum_bps = u16::from_le_bytes(fundraiser_mutable.quorum_bps) as u128;
+        let threshold_bps = u16::from_le_bytes(fundraiser_mutable.threshold_bps) as u128;
+        let vote_duration = u64::from_le_bytes(fundraiser_mutable.vote_duration) as i64;
+
+        let milestone = &mut fundraiser_mutable.milestones[index];
+
+        assert!(
+            milestone.status[0] == MILESTONE_SUBMITTED,
+            "Milestone not submitted"
+        );
+        assert!(
+            Clock::get()?.unix_timestamp
+                >= i64::from_le_bytes(milestone.submitted_at) + vote_duration,
+            "Voting still open"
+        );
+
+        let approve_weight = u64::from_le_bytes(milestone.approve_weight) as u128;
+        let cast_weight = approve_weight + u64::from_le_bytes(milestone.reject_weight) as u128;
+
+        let quorum_reached = cast_weight * 10_000 >= total_weight * quorum_bps;
+        let threshold_reached =
+            cast_weight > 0 && approve_weight * 10_000 >= cast_weight * threshold_bps;
+        let passed = quorum_reached && threshold_reached;
+
+        milestone.status = if passed {
+            [MILESTONE_RELEASED]
+        } else {
+            [MILESTONE_FROZEN]
+        };
+
+        (passed, u16::from_le_bytes(milestone.bps))
+    };
+
+    if passed {
+        pay_out(fundraiser, payout_accounts, bps)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,215 @@
+use pinocchio::{
+    cpi::{Seed, Signer},
+    error::ProgramError,
+    sysvars::{clock::Clock, rent::Rent, Sysvar},
+    AccountView, Address, ProgramResult,
+};
+use pinocchio_system::instructions::CreateAccount;
+use pinocchio_token::{instructions::Transfer, state::TokenAccount};
+
+use crate::instructions::{
+    assert_not_denylisted, check_contributor, freeze_receipts, load_config, thaw_receipts,
+};
+use crate::state::{Contributor, Fundraiser};
+
+// contributor hands part or all of their position, refund rights included, to another wallet
+// data: [amount (u64)]
+// accounts: [contributor, recipient, fundraiser, contributor state, recipient state,
+//            system program, config, denylist (only once created),
+//            receipt mint, contributor receipt account, recipient receipt account (only with a
+//            receipt mint)]
+pub fn process_transfer_contribution_instruction(
+    accounts: &[AccountView],
+    data: &[u8],
+) -> ProgramResult {
+    let [contributor, recipient, fundraiser, contributor_state_account, recipient_state_account, _system_program, config, rest @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let amount = u64::from_le_bytes(
+        data.get(..8)
+            .ok_or(ProgramError::InvalidInstructionData)?
+            .try_into()
+            .unwrap(),
+    );
+
+    assert!(contributor.is_signer(), "Contributor must be signer");
+    assert!(
+        fundraiser.owned_by(&crate::ID),
+        "Invalid Fundraiser Account"
+    );
+    assert!(
+        contributor_state_account.owned_by(&crate::ID),
+        "Invalid Contributor Account"
+    );
+    assert!(
+        contributor.address().ne(recipient.address()),
+        "Cannot transfer to self"
+    );
+    assert!(amount > 0, "Nothing to transfer");
+
+    check_contributor(contributor_state_account, fundraiser, contributor);
+
+    let (receipt_mint, creator, fundraiser_bump) = {
+        let fundraiser_data = fundraiser.try_borrow().unwrap();
+        let fundraiser_state =
+            bytemuck::from_bytes::<Fundraiser>(&fundraiser_data[..Fundraiser::LEN]);
+
+        // splitting a position across wallets would farm quadratic funding matching
+        assert!(
+            !fundraiser_state.has_round(),
+            "Round contributions can't be transferred"
+        );
+        // the tier reward was minted to the contributor and can't follow a split position
+        assert!(
+            fundraiser_state.tiers_count[0] == 0,
+            "Tiered contributions can't be transferred"
+        );
+        // gated raises only take positions from wallets that passed the gate at deposit
+        assert!(
+            !fundraiser_state.has_allowlist() && !fundraiser_state.has_attestor(),
+            "Gated contributions can't be transferred"
+        );
+
+        (
+            fundraiser_state
+                .has_receipt_mint()
+                .then_some(fundraiser_state.receipt_mint),
+            fundraiser_state.creator,
+            fundraiser_state.bump,
+        )
+    };
+
+    // the recipient gets refund rights, so it must pass the denylist like a depositor
+    let mut rest = rest.iter();
+    if load_config(config).has_denylist() {
+        let denylist = rest.next().expect("Denylist missing");
+        assert_not_denylisted(denylist, &[recipient.address()], "Recipient denylisted");
+    }
+
+    let recipient_bump = check_contributor(recipient_state_account, fundraiser, recipient);
+
+    // if recipient state doesn't exist, the contributor pays for it
+    if recipient_state_account.is_data_empty() {
+        let bump = recipient_bump.to_le_bytes();
+        let recipient_seeds = [
+            Seed::from(b"contributor"),
+            Seed::from(fundraiser.address().as_ref()),
+            Seed::from(recipient.address().as_ref()),
+            Seed::from(&bump),
+        ];
+
+        CreateAccount {
+            from: contributor,
+            lamports: Rent::get()?.minimum_balance_unchecked(Contributor::LEN),
+            owner: &crate::ID,
+            space: Contributor::LEN as u64,
+            to: recipient_state_account,
+        }
+        .invoke_signed(&[Signer::from(&recipient_seeds)])?;
+    }
+
+    {
+        let mut contributor_state_data = contributor_state_account.try_borrow_mut().unwrap();
+        let contributor_mutable =
+            bytemuck::from_bytes_mut::<Contributor>(&mut contributor_state_data);
+
+        let mut recipient_state_data = recipient_state_account.try_borrow_mut().unwrap();
+        let recipient_mutable = bytemuck::from_bytes_mut::<Contributor>(&mut recipient_state_data);
+
+        // weight backing an open vote stays put until the vote closes
+        assert!(
+            !contributor_mutable.is_locked(Clock::get()?.unix_timestamp),
+            "Contribution locked by an open vote"
+        );
+
+        let total = u64::from_le_bytes(contributor_mutable.amount);
+        assert!(amount <= total, "Amount exceeds contribution");
+
+        // per mint amounts move pro-rata, a full transfer moves them exactly
+        for (from, to) in contributor_mutable
+            .amounts
+            .iter_mut()
+            .zip(recipient_mutable.amounts.iter_mut())
+        {
+            let moved = (u64::from_le_bytes(*from) as u128 * amount as u128 / total as u128) as u64;
+
+            *from = (u64::from_le_bytes(*from) - moved).to_le_bytes();
+            *to = (u64::from_le_bytes(*to) + moved).to_le_bytes();
+        }
+
+        contributor_mutable.amount = (total - amount).to_le_bytes();
+        recipient_mutable.amount =
+            (u64::from_le_bytes(recipient_mutable.amount) + amount).to_le_bytes();
+    }
+
+    // receipts follow the position so the recipient can burn them on refund
+    if let Some(receipt_mint_address) = receipt_mint {
+        let receipt_mint = rest.next().expect("Receipt mint missing");
+        let contributor_receipt = rest.next().expect("Receipt account missing");
+        let recipient_receipt = rest.next().expect("Receipt account missing");
+
+        assert!(
+            receipt_mint
+                .address()
+                .eq(&Address::new_from_array(receipt_mint_address)),
+            "Receipt mint does not match"
+        );
+
+        {
+            let recipient_receipt_state = TokenAccount::from_account_view(recipient_receipt)?;
+            assert!(
+                recipient_receipt_state.mint().eq(receipt_mint.address()),
+                "Receipt mint does not match"
+            );
+            assert!(
+                recipient_receipt_state.owner().eq(recipient.address()),
+                "Receipt account not owned by recipient"
+            );
+        }
+
+        let fundraiser_seeds = [
+            Seed::from(b"fundraiser"),
+            Seed::from(creator.as_ref()),
+            Seed::from(&fundraiser_bump),
+        ];
+
+        thaw_receipts(
+            contributor_receipt,
+            receipt_mint,
+            fundraiser,
+            &fundraiser_seeds,
+        )?;
+        thaw_receipts(
+            recipient_receipt,
+            receipt_mint,
+            fundraiser,
+            &fundraiser_seeds,
+        )?;
+
+        Transfer {
+            amount,
+            authority: contributor,
+            from: contributor_receipt,
+            to: recipient_receipt,
+        }
+        .invoke()?;
+
+        freeze_receipts(
+            contributor_receipt,
+            receipt_mint,
+            fundraiser,
+            &fundraiser_seeds,
+        )?;
+        freeze_receipts(
+            recipient_receipt,
+            receipt_mint,
+            fundraiser,
+            &fundraiser_seeds,
+        )?;
+    }
+
+    Ok(())
+}
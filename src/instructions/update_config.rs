@@ -0,0 +1,38 @@
+use pinocchio::{error::ProgramError, AccountView, Address, ProgramResult};
+
+use crate::instructions::{check_config, ConfigData};
+use crate::state::Config;
+
+// admin changes the platform fee - live fundraisers keep the fee they were created with - and
+// the oracle program trusted for price feeds
+// accounts: [admin, config]
+pub fn process_update_config_instruction(accounts: &[AccountView], data: &[u8]) -> ProgramResult {
+    let [admin, config, _extra @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let parsed_data = bytemuck::from_bytes::<ConfigData>(&data[..ConfigData::LEN]);
+
+    assert!(admin.is_signer(), "Admin must be signer");
+    check_config(config);
+    assert!(
+        u16::from_le_bytes(parsed_data.fee_bps) <= 10_000,
+        "Invalid fee"
+    );
+
+    let mut config_data = config.try_borrow_mut().unwrap();
+    let config_mutable = bytemuck::from_bytes_mut::<Config>(&mut config_data);
+
+    assert!(
+        admin
+            .address()
+            .eq(&Address::new_from_array(config_mutable.admin)),
+        "Admin does not match"
+    );
+
+    config_mutable.fee_bps = parsed_data.fee_bps;
+    config_mutable.fee_recipient = parsed_data.fee_recipient;
+    config_mutable.oracle_program = parsed_data.oracle_program;
+
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use pinocchio::{error::ProgramError, AccountView, Address, ProgramResult};
+
+use crate::instructions::check_config;
+use crate::state::{Config, Denylist, DENYLIST_ADD, DENYLIST_REMOVE, MAX_DENYLIST_ENTRIES};
+
+// admin adds or removes a wallet from the denylist
+// data: [DENYLIST_ADD / DENYLIST_REMOVE, wallet (32)]
+// accounts: [admin, config, denylist]
+pub fn process_update_denylist_instruction(accounts: &[AccountView], data: &[u8]) -> ProgramResult {
+    let [admin, config, denylist, _extra @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let (action, wallet) = data
+        .split_first()
+        .ok_or(ProgramError::InvalidInstructionData)?;
+    let wallet: [u8; 32] = wallet
+        .get(..32)
+        .ok_or(ProgramError::InvalidInstructionData)?
+        .try_into()
+        .unwrap();
+
+    assert!(admin.is_signer(), "Admin must be signer");
+    check_config(config);
+    assert!(denylist.owned_by(&crate::ID), "Invalid Denylist Account");
+
+    {
+        let config_data = config.try_borrow().unwrap();
+        assert!(
+            admin.address().eq(&Address::new_from_array(
+                bytemuck::from_bytes::<Config>(&config_data).admin
+            )),
+            "Admin does not match"
+        );
+    }
+
+    let mut denylist_data = denylist.try_borrow_mut().unwrap();
+    let denylist_mutable = bytemuck::from_bytes_mut::<Denylist>(&mut denylist_data);
+
+    let count = u16::from_le_bytes(denylist_mutable.count) as usize;
+    let position = denylist_mutable
+        .entries()
+        .iter()
+        .position(|entry| entry == &wallet);
+
+    match *action {
+        DENYLIST_ADD => {
+            assert!(position.is_none(), "Already denylisted");
+            assert!(count < MAX_DENYLIST_ENTRIES, "Denylist full");
+
+            denylist_mutable.addresses[count] = wallet;
+            denylist_mutable.count = (count as u16 + 1).to_le_bytes();
+        }
+        DENYLIST_REMOVE => {
+            let position = position.expect("Not denylisted");
+
+            // order doesn't matter - the last entry takes the removed slot
+            denylist_mutable.addresses[position] = denylist_mutable.addresses[count - 1];
+            denylist_mutable.addresses[count - 1] = [0u8; 32];
+            denylist_mutable.count = (count as u16 - 1).to_le_bytes();
+        }
+        _ => return Err(ProgramError::InvalidInstructionData),
+    }
+
+    Ok(())
+}
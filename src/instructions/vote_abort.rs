@@ -0,0 +1,117 @@
+use pinocchio::{
+    cpi::{Seed, Signer},
+    error::ProgramError,
+    sysvars::{clock::Clock, rent::Rent, Sysvar},
+    AccountView, Address, ProgramResult,
+};
+use pinocchio_system::instructions::CreateAccount;
+
+use crate::instructions::check_contributor;
+use crate::state::{Contributor, Proposal, Vote, ABORT_VOTE, PROPOSAL_OPEN};
+
+// contributor votes on the kill switch, weighted by their amount
+// data: [abort (1) / keep (0)]
+// accounts: [contributor, fundraiser, contributor state, proposal, vote, system program]
+pub fn process_vote_abort_instruction(accounts: &[AccountView], data: &[u8]) -> ProgramResult {
+    let [contributor, fundraiser, contributor_state_account, proposal, vote, _system_program, _extra @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let abort = *data.first().ok_or(ProgramError::InvalidInstructionData)? == 1;
+
+    assert!(contributor.is_signer(), "Contributor must be signer");
+    assert!(
+        contributor_state_account.owned_by(&crate::ID),
+        "Invalid Contributor Account"
+    );
+    assert!(proposal.owned_by(&crate::ID), "Invalid Proposal Account");
+
+    check_contributor(contributor_state_account, fundraiser, contributor);
+
+    let current_time_unix = Clock::get()?.unix_timestamp;
+
+    let mut proposal_data = proposal.try_borrow_mut().unwrap();
+    let proposal_mutable = bytemuck::from_bytes_mut::<Proposal>(&mut proposal_data);
+
+    assert!(
+        fundraiser
+            .address()
+            .eq(&Address::new_from_array(proposal_mutable.fundraiser)),
+        "Fundraiser does not match"
+    );
+    assert!(
+        proposal_mutable.status[0] == PROPOSAL_OPEN,
+        "Proposal not open"
+    );
+
+    let voting_ends = i64::from_le_bytes(proposal_mutable.ends_at);
+    let nonce = proposal_mutable.nonce;
+    assert!(current_time_unix < voting_ends, "Voting closed");
+
+    // weight is locked until the vote closes
+    let mut contributor_state_data = contributor_state_account.try_borrow_mut().unwrap();
+    let contributor_mutable = bytemuck::from_bytes_mut::<Contributor>(&mut contributor_state_data);
+
+    let weight = u64::from_le_bytes(contributor_mutable.amount);
+    assert!(weight > 0, "Nothing to vote with");
+
+    if i64::from_le_bytes(contributor_mutable.locked_until) < voting_ends {
+        contributor_mutable.locked_until = voting_ends.to_le_bytes();
+    }
+
+    if abort {
+        let abort_weight = u64::from_le_bytes(proposal_mutable.abort_weight) + weight;
+        proposal_mutable.abort_weight = abort_weight.to_le_bytes();
+    } else {
+        let keep_weight = u64::from_le_bytes(proposal_mutable.keep_weight) + weight;
+        proposal_mutable.keep_weight = keep_weight.to_le_bytes();
+    }
+
+    drop(contributor_state_data);
+    drop(proposal_data);
+
+    // one vote per contributor per proposal, in the vote pda slot reserved for the abort proposal
+    let abort_seed = [ABORT_VOTE];
+    let seed = [
+        b"vote",
+        fundraiser.address().as_ref(),
+        abort_seed.as_ref(),
+        contributor.address().as_ref(),
+        nonce.as_ref(),
+    ];
+    let (created_vote, vote_bump) = Address::find_program_address(&seed, &crate::ID);
+
+    assert!(&created_vote.eq(vote.address()), "Vote does not match");
+
+    let bump = vote_bump.to_le_bytes();
+    let vote_seeds = [
+        Seed::from(b"vote"),
+        Seed::from(fundraiser.address().as_ref()),
+        Seed::from(&abort_seed),
+        Seed::from(contributor.address().as_ref()),
+        Seed::from(&nonce),
+        Seed::from(&bump),
+    ];
+
+    CreateAccount {
+        from: contributor,
+        lamports: Rent::get()?.minimum_balance_unchecked(Vote::LEN),
+        owner: &crate::ID,
+        space: Vote::LEN as u64,
+        to: vote,
+    }
+    .invoke_signed(&[Signer::from(&vote_seeds)])?;
+
+    let mut vote_data = vote.try_borrow_mut().unwrap();
+    let vote_mutable = bytemuck::from_bytes_mut::<Vote>(&mut vote_data);
+
+    vote_mutable.fundraiser = fundraiser.address().as_ref().try_into().unwrap();
+    vote_mutable.contributor = contributor.address().as_ref().try_into().unwrap();
+    vote_mutable.milestone = abort_seed;
+    vote_mutable.approve = [abort as u8];
+    vote_mutable.weight = weight.to_le_bytes();
+
+    Ok(())
+}
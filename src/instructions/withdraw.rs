@@ -0,0 +1,82 @@
+use pinocchio::{
+    error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    AccountView, Address, ProgramResult,
+};
+
+use crate::instructions::{check_maker, split_transfer};
+use crate::state::{AcceptedMint, Fundraiser, MAX_ACCEPTED_MINTS};
+
+// maker withdraws whatever vested since the last withdrawal, for every accepted mint
+// accounts: [maker, fundraiser, token program, ...multisig signers, ...(vault, ...recipient token accounts) per accepted mint]
+pub fn process_withdraw_instruction(accounts: &[AccountView]) -> ProgramResult {
+    let [maker, fundraiser, _token_program, rest @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    assert!(
+        fundraiser.owned_by(&crate::ID),
+        "Invalid Fundraiser Account"
+    );
+
+    let current_time_unix = Clock::get()?.unix_timestamp;
+
+    let mut fundraiser_data = fundraiser.try_borrow_mut().unwrap();
+    let fundraiser_state = *bytemuck::from_bytes::<Fundraiser>(&fundraiser_data[..Fundraiser::LEN]);
+
+    let payout_accounts = check_maker(maker, &fundraiser_state.maker, rest);
+
+    assert!(
+        fundraiser_state.is_successful(current_time_unix),
+        "Fundraiser not successful"
+    );
+    assert!(fundraiser_state.is_vesting(), "Funds are not vested");
+    assert!(!fundraiser_state.is_paused(), "Fundraiser paused");
+    assert!(!fundraiser_state.awaits_round(), "Round not distributed");
+
+    let accepted_mints = Fundraiser::accepted_mints_mut(&mut fundraiser_data);
+
+    let chunk_len = 1 + fundraiser_state.payout_destinations_count();
+    assert!(
+        payout_accounts.len() == accepted_mints.len() * chunk_len,
+        "Every accepted mint vault must be passed"
+    );
+
+    // book every withdrawal first so the fundraiser isn't borrowed while it signs
+    let mut amounts = [0u64; MAX_ACCEPTED_MINTS];
+
+    for ((accepted_mint, accounts), amount) in accepted_mints
+        .iter_mut()
+        .zip(payout_accounts.chunks_exact(chunk_len))
+        .zip(amounts.iter_mut())
+    {
+        assert!(
+            accounts[0]
+                .address()
+                .eq(&Address::new_from_array(accepted_mint.vault)),
+            "vault do not match"
+        );
+
+        *amount = withdrawable(&fundraiser_state, accepted_mint, current_time_unix);
+
+        let withdrawn = u64::from_le_bytes(accepted_mint.withdrawn_amount) + *amount;
+        accepted_mint.withdrawn_amount = withdrawn.to_le_bytes();
+    }
+
+    drop(fundraiser_data);
+
+    for (accounts, amount) in payout_accounts.chunks_exact(chunk_len).zip(amounts.iter()) {
+        let (vault, destinations) = accounts.split_first().unwrap();
+
+        split_transfer(&fundraiser_state, fundraiser, vault, destinations, *amount)?;
+    }
+
+    Ok(())
+}
+
+// vested - already withdrawn
+fn withdrawable(fundraiser: &Fundraiser, accepted_mint: &AcceptedMint, now: i64) -> u64 {
+    let vested = fundraiser.vested(u64::from_le_bytes(accepted_mint.current_amount), now);
+
+    vested - u64::from_le_bytes(accepted_mint.withdrawn_amount)
+}
@@ -0,0 +1,209 @@
+use pinocchio::{
+    cpi::{Seed, Signer},
+    error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    AccountView, Address, ProgramResult,
+};
+use pinocchio_token::{
+    instructions::{Burn, Transfer},
+    state::TokenAccount,
+};
+
+use crate::instructions::{
+    assert_not_denylisted, check_contributor, freeze_receipts, load_config, thaw_receipts,
+};
+use crate::state::{Contributor, Fundraiser, MAX_ACCEPTED_MINTS};
+
+// contributor pulls their whole contribution out of a raise that allows it, before the deadline
+// the penalty share stays in the vaults and still counts towards the goal
+// accounts: [contributor, fundraiser, contributor state, token program, config,
+//            denylist (only once created), ...(vault, contributor token account) per accepted mint,
+//            receipt mint, contributor receipt account if the fundraiser has a receipt mint]
+pub fn process_withdraw_contribution_instruction(accounts: &[AccountView]) -> ProgramResult {
+    let [contributor, fundraiser, contributor_state_account, _token_program, config, refund_accounts @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    assert!(contributor.is_signer(), "Contributor must be signer");
+    assert!(
+        fundraiser.owned_by(&crate::ID),
+        "Invalid Fundraiser Account"
+    );
+    assert!(
+        contributor_state_account.owned_by(&crate::ID),
+        "Invalid Contributor Account"
+    );
+
+    check_contributor(contributor_state_account, fundraiser, contributor);
+
+    // refunds below are paid to contributor owned token accounts, never to a denylisted wallet
+    let refund_accounts = if load_config(config).has_denylist() {
+        let (denylist, refund_accounts) = refund_accounts.split_first().expect("Denylist missing");
+        assert_not_denylisted(
+            denylist,
+            &[contributor.address()],
+            "Refund destination denylisted",
+        );
+
+        refund_accounts
+    } else {
+        refund_accounts
+    };
+
+    let current_time_unix = Clock::get()?.unix_timestamp;
+
+    // zeroing the contributor makes the withdrawal one shot
+    let (credited, amounts) = {
+        let mut contributor_state_data = contributor_state_account.try_borrow_mut().unwrap();
+        let contributor_mutable =
+            bytemuck::from_bytes_mut::<Contributor>(&mut contributor_state_data);
+
+        assert!(
+            !contributor_mutable.is_locked(current_time_unix),
+            "Contribution locked by an open vote"
+        );
+
+        let credited = u64::from_le_bytes(contributor_mutable.amount);
+        assert!(credited > 0, "Nothing to withdraw");
+
+        let amounts = contributor_mutable.amounts;
+
+        contributor_mutable.amount = 0u64.to_le_bytes();
+        contributor_mutable.amounts = [[0u8; 8]; MAX_ACCEPTED_MINTS];
+        contributor_mutable.tier = [0];
+
+        (credited, amounts)
+    };
+
+    // the refunded share leaves the raise totals, the penalty stays in them
+    let (fundraiser_state, vaults, refunds, mints_count) = {
+        let mut fundraiser_data = fundraiser.try_borrow_mut().unwrap();
+        let fundraiser_mutable =
+            bytemuck::from_bytes_mut::<Fundraiser>(&mut fundraiser_data[..Fundraiser::LEN]);
+
+        assert!(
+            fundraiser_mutable.allows_early_withdraw(),
+            "Early withdraw not allowed"
+        );
+        assert!(
+            fundraiser_mutable.end_time() > current_time_unix as u64,
+            "Fundraising closed"
+        );
+        assert!(!fundraiser_mutable.is_aborted(), "Fundraiser aborted");
+
+        // quadratic funding weights and matched shares can't be taken back once counted
+        assert!(
+            !fundraiser_mutable.has_round(),
+            "Round contributions can't be withdrawn"
+        );
+        assert!(
+            fundraiser_mutable.has_matching[0] == 0,
+            "Matched contributions can't be withdrawn"
+        );
+
+        let kept_bps = 10_000 - u16::from_le_bytes(fundraiser_mutable.early_withdraw_penalty_bps);
+        let refund = |amount: u64| (amount as u128 * kept_bps as u128 / 10_000) as u64;
+
+        let raised = u64::from_le_bytes(fundraiser_mutable.current_amount);
+        fundraiser_mutable.current_amount = (raised - refund(credited)).to_le_bytes();
+
+        let contributed = u64::from_le_bytes(fundraiser_mutable.contributed_amount);
+        fundraiser_mutable.contributed_amount = (contributed - credited).to_le_bytes();
+
+        let fundraiser_state = *fundraiser_mutable;
+
+        let mut vaults = [[0u8; 32]; MAX_ACCEPTED_MINTS];
+        let mut refunds = [0u64; MAX_ACCEPTED_MINTS];
+
+        let accepted_mints = Fundraiser::accepted_mints_mut(&mut fundraiser_data);
+        for (index, accepted_mint) in accepted_mints.iter_mut().enumerate() {
+            refunds[index] = refund(u64::from_le_bytes(amounts[index]));
+            vaults[index] = accepted_mint.vault;
+
+            let raised_for_mint = u64::from_le_bytes(accepted_mint.current_amount);
+            accepted_mint.current_amount = (raised_for_mint - refunds[index]).to_le_bytes();
+        }
+
+        (fundraiser_state, vaults, refunds, accepted_mints.len())
+    };
+
+    let bump = fundraiser_state.bump;
+    let fundraiser_seeds = [
+        Seed::from(b"fundraiser"),
+        Seed::from(fundraiser_state.creator.as_ref()),
+        Seed::from(&bump),
+    ];
+
+    // receipts of the withdrawn position are burnt
+    let refund_accounts = if fundraiser_state.has_receipt_mint() {
+        let (refund_accounts, receipt_accounts) = refund_accounts
+            .split_at_checked(mints_count * 2)
+            .expect("Every accepted mint vault must be passed");
+        let [receipt_mint, receipt_account] = receipt_accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        assert!(
+            receipt_mint
+                .address()
+                .eq(&Address::new_from_array(fundraiser_state.receipt_mint)),
+            "Receipt mint does not match"
+        );
+
+        thaw_receipts(receipt_account, receipt_mint, fundraiser, &fundraiser_seeds)?;
+
+        Burn {
+            account: receipt_account,
+            mint: receipt_mint,
+            authority: contributor,
+            amount: credited,
+        }
+        .invoke()?;
+
+        freeze_receipts(receipt_account, receipt_mint, fundraiser, &fundraiser_seeds)?;
+
+        refund_accounts
+    } else {
+        refund_accounts
+    };
+
+    assert!(
+        refund_accounts.len() == mints_count * 2,
+        "Every accepted mint vault must be passed"
+    );
+
+    for ((vault_address, accounts), amount) in vaults[..mints_count]
+        .iter()
+        .zip(refund_accounts.chunks_exact(2))
+        .zip(refunds.iter())
+    {
+        let (vault, destination) = (&accounts[0], &accounts[1]);
+
+        assert!(
+            vault.address().eq(&Address::new_from_array(*vault_address)),
+            "vault do not match"
+        );
+        assert!(
+            TokenAccount::from_account_view(destination)?
+                .owner()
+                .eq(contributor.address()),
+            "Destination not owned by contributor"
+        );
+
+        if *amount == 0 {
+            continue;
+        }
+
+        Transfer {
+            amount: *amount,
+            authority: fundraiser,
+            from: vault,
+            to: destination,
+        }
+        .invoke_signed(&[Signer::from(&fundraiser_seeds)])?;
+    }
+
+    Ok(())
+}
@@ -33,6 +33,88 @@ pub fn process_instruction(
         FundraiserInstruction::Deposit => {
             instructions::process_contribute_instruction(accounts, data)
         }
+        FundraiserInstruction::FundMatching => {
+            instructions::process_fund_matching_instruction(accounts, data)
+        }
+        FundraiserInstruction::FinalizeMatching => {
+            instructions::process_finalize_matching_instruction(accounts)
+        }
+        FundraiserInstruction::InitRound => {
+            instructions::process_init_round_instruction(accounts, data)
+        }
+        FundraiserInstruction::JoinRound => instructions::process_join_round_instruction(accounts),
+        FundraiserInstruction::DistributeMatching => {
+            instructions::process_distribute_matching_instruction(accounts)
+        }
+        FundraiserInstruction::Claim => instructions::process_claim_instruction(accounts),
+        FundraiserInstruction::SubmitMilestone => {
+            instructions::process_submit_milestone_instruction(accounts, data)
+        }
+        FundraiserInstruction::ApproveMilestone => {
+            instructions::process_approve_milestone_instruction(accounts, data)
+        }
+        FundraiserInstruction::ReleaseTranche => {
+            instructions::process_release_tranche_instruction(accounts, data)
+        }
+        FundraiserInstruction::CastVote => {
+            instructions::process_cast_vote_instruction(accounts, data)
+        }
+        FundraiserInstruction::TallyMilestone => {
+            instructions::process_tally_milestone_instruction(accounts, data)
+        }
+        FundraiserInstruction::ProposeAbort => {
+            instructions::process_propose_abort_instruction(accounts)
+        }
+        FundraiserInstruction::VoteAbort => {
+            instructions::process_vote_abort_instruction(accounts, data)
+        }
+        FundraiserInstruction::FinalizeAbort => {
+            instructions::process_finalize_abort_instruction(accounts)
+        }
+        FundraiserInstruction::ClaimRemainder => {
+            instructions::process_claim_remainder_instruction(accounts)
+        }
+        FundraiserInstruction::Withdraw => instructions::process_withdraw_instruction(accounts),
+        FundraiserInstruction::SetBeneficiary => {
+            instructions::process_set_beneficiary_instruction(accounts)
+        }
+        FundraiserInstruction::InitConfig => {
+            instructions::process_init_config_instruction(accounts, data)
+        }
+        FundraiserInstruction::UpdateConfig => {
+            instructions::process_update_config_instruction(accounts, data)
+        }
+        FundraiserInstruction::SetPaused => {
+            instructions::process_set_paused_instruction(accounts, data)
+        }
+        FundraiserInstruction::Pause => instructions::process_pause_instruction(accounts),
+        FundraiserInstruction::Resume => instructions::process_resume_instruction(accounts, data),
+        FundraiserInstruction::ProposeMakerTransfer => {
+            instructions::process_propose_maker_transfer_instruction(accounts)
+        }
+        FundraiserInstruction::AcceptMakerTransfer => {
+            instructions::process_accept_maker_transfer_instruction(accounts)
+        }
+        FundraiserInstruction::InitMultisig => {
+            instructions::process_init_multisig_instruction(accounts, data)
+        }
+        FundraiserInstruction::InitDenylist => {
+            instructions::process_init_denylist_instruction(accounts)
+        }
+        FundraiserInstruction::UpdateDenylist => {
+            instructions::process_update_denylist_instruction(accounts, data)
+        }
+        FundraiserInstruction::TransferContribution => {
+            instructions::process_transfer_contribution_instruction(accounts, data)
+        }
+        FundraiserInstruction::WithdrawContribution => {
+            instructions::process_withdraw_contribution_instruction(accounts)
+        }
+        FundraiserInstruction::Pledge => instructions::process_pledge_instruction(accounts, data),
+        FundraiserInstruction::Settle => instructions::process_settle_instruction(accounts),
+        FundraiserInstruction::DepositFor => {
+            instructions::process_contribute_for_instruction(accounts, data)
+        }
         _ => return Err(ProgramError::InvalidInstructionData),
     }
     // Ok(())
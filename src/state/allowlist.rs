@@ -0,0 +1,24 @@
+use solana_sha256_hasher::hashv;
+
+// leaf of a fundraiser allowlist tree - a contributor address with its cap (0 - uncapped)
+pub fn allowlist_leaf(contributor: &[u8], cap: u64) -> [u8; 32] {
+    hashv(&[&[0u8], contributor, &cap.to_le_bytes()]).to_bytes()
+}
+
+// pairs are hashed sorted so proofs don't need left / right flags
+pub fn allowlist_node(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (left, right) = if a <= b { (a, b) } else { (b, a) };
+
+    hashv(&[&[1u8], left, right]).to_bytes()
+}
+
+// proof: sibling hashes from the leaf up, 32 bytes each
+pub fn verify_allowlist_proof(root: &[u8; 32], contributor: &[u8], cap: u64, proof: &[u8]) -> bool {
+    let computed = proof
+        .chunks_exact(32)
+        .fold(allowlist_leaf(contributor, cap), |node, sibling| {
+            allowlist_node(&node, sibling.try_into().unwrap())
+        });
+
+    computed == *root
+}
@@ -0,0 +1,30 @@
+use bytemuck::{Pod, Zeroable};
+
+// program wide settings, singleton - seeds ["config"]
+#[repr(C)]
+#[derive(Pod, Zeroable, Clone, Copy)]
+pub struct Config {
+    pub admin: [u8; 32],
+    pub fee_bps: [u8; 2], // platform cut of every payout, snapshotted at fundraiser init
+    pub fee_recipient: [u8; 32], // owner of the token accounts fees are paid into
+    pub paused: [u8; 1],  // no new fundraiser or deposit while set, refunds still go through
+    pub has_denylist: [u8; 1], // deposits must pass the denylist once it was created
+    pub oracle_program: [u8; 32], // owner every usd mode price feed must have
+    pub bump: [u8; 1],
+}
+
+impl Config {
+    pub const LEN: usize = core::mem::size_of::<Config>();
+
+    pub fn to_bytes(&self) -> &[u8; Self::LEN] {
+        bytemuck::bytes_of(self).try_into().unwrap()
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused[0] == 1
+    }
+
+    pub fn has_denylist(&self) -> bool {
+        self.has_denylist[0] == 1
+    }
+}
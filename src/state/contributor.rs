@@ -1,9 +1,16 @@
 use bytemuck::{Pod, Zeroable};
 
+use crate::state::MAX_ACCEPTED_MINTS;
+
 #[repr(C)]
 #[derive(Pod, Zeroable, Clone, Copy)]
 pub struct Contributor {
-    pub amount: [u8; 8],
+    pub amount: [u8; 8], // total across every mint, in usd for usd mode fundraisers
+    pub amounts: [[u8; 8]; MAX_ACCEPTED_MINTS], // indexed like the fundraiser accepted mints
+    pub locked_until: [u8; 8], // i64, set while the amount backs an open milestone vote
+    pub tier: [u8; 1],   // reward tier index + 1 whose receipt was minted, zeroed if none
+    pub pledged: [u8; 8], // delegated to the fundraiser, moves into `amount` once settled
+    pub pledge_account: [u8; 32], // token account the pledge is pulled from
 }
 
 impl Contributor {
@@ -12,4 +19,9 @@ impl Contributor {
     pub fn to_bytes(&self) -> &[u8; Self::LEN] {
         bytemuck::bytes_of(self).try_into().unwrap()
     }
+
+    // amount can't leave (refund, transfer...) while it weighs in an open vote
+    pub fn is_locked(&self, now: i64) -> bool {
+        i64::from_le_bytes(self.locked_until) > now
+    }
 }
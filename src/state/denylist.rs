@@ -0,0 +1,33 @@
+use bytemuck::{Pod, Zeroable};
+
+// max wallets the program wide denylist can hold
+pub const MAX_DENYLIST_ENTRIES: usize = 128;
+
+pub const DENYLIST_ADD: u8 = 0;
+pub const DENYLIST_REMOVE: u8 = 1;
+
+// sanctioned wallets that can't deposit or receive refunds, managed by the config admin
+// singleton - seeds ["denylist"]
+#[repr(C)]
+#[derive(Pod, Zeroable, Clone, Copy)]
+pub struct Denylist {
+    pub count: [u8; 2],
+    pub addresses: [[u8; 32]; MAX_DENYLIST_ENTRIES],
+    pub bump: [u8; 1],
+}
+
+impl Denylist {
+    pub const LEN: usize = core::mem::size_of::<Denylist>();
+
+    pub fn to_bytes(&self) -> &[u8; Self::LEN] {
+        bytemuck::bytes_of(self).try_into().unwrap()
+    }
+
+    pub fn entries(&self) -> &[[u8; 32]] {
+        &self.addresses[..u16::from_le_bytes(self.count) as usize]
+    }
+
+    pub fn contains(&self, address: &[u8]) -> bool {
+        self.entries().iter().any(|entry| entry.as_ref() == address)
+    }
+}
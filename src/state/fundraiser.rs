@@ -1,17 +1,95 @@
 use bytemuck::{Pod, Zeroable};
 use pinocchio::Address;
 
+use crate::state::{Milestone, RewardTier, MAX_MILESTONES, MAX_REWARD_TIERS};
+
+// max number of mints a single fundraiser can accept
+pub const MAX_ACCEPTED_MINTS: usize = 4;
+
+// max recipients a payout can be split between
+pub const MAX_PAYOUT_SPLITS: usize = 8;
+
+// max seconds pauses can push the deadline back, in total
+pub const MAX_DEADLINE_EXTENSION: u64 = 30 * 24 * 60 * 60;
+
+// how `amount_to_raise` / `current_amount` are denominated
+pub const GOAL_MODE_TOKEN: u8 = 0; // raw token units, summed across mints
+pub const GOAL_MODE_USD: u8 = 1; // usd, every deposit converted with the mint price feed
+
 #[repr(C)]
 #[derive(Pod, Zeroable, Clone, Copy)]
 pub struct Fundraiser {
-    pub maker: [u8; 32],
-    pub mint_to_raise: [u8; 32],
+    pub maker: [u8; 32],         // controls the fundraiser, can be handed over
+    pub mint_to_raise: [u8; 32], // primary mint - also entry 0 of the accepted mints
     pub vault: [u8; 32],
-    pub amount_to_raise: [u8; 8],
-    pub current_amount: [u8; 8],
-    pub time_started: [u8; 8], // i64 / u64
-    pub duration: [u8; 1],     // in days
+    pub amount_to_raise: [u8; 8], // sum of every accepted mint goal, or usd goal in usd mode
+    pub current_amount: [u8; 8],  // sum of every accepted mint deposit, or usd raised in usd mode
+    pub time_started: [u8; 8],    // i64 / u64
+    pub duration: [u8; 1],        // in days
     pub bump: [u8; 1],
+    pub accepted_mints_count: [u8; 1],
+    pub goal_mode: [u8; 1],
+    pub max_price_age: [u8; 8], // seconds, usd mode only
+    pub max_conf_bps: [u8; 2],  // max conf / price ratio, usd mode only
+    pub has_matching: [u8; 1],
+    pub matched_amount: [u8; 8], // sponsor tokens moved into the vault, primary mint units
+    pub round: [u8; 32],         // quadratic funding round joined, zeroed if none
+    pub claimed: [u8; 1],
+    pub approver: [u8; 32], // approves submitted milestones, zeroed when contributors vote
+    pub contributed_amount: [u8; 8], // sum of every contributor amount - the total vote weight
+    pub quorum_bps: [u8; 2], // share of the total weight that must vote
+    pub threshold_bps: [u8; 2], // share of the cast weight that must approve
+    pub vote_duration: [u8; 8], // seconds a milestone vote stays open
+    pub aborted: [u8; 1],   // contributors voted to abort, nothing more is paid out
+    pub milestones_count: [u8; 1],
+    pub milestones: [Milestone; MAX_MILESTONES],
+    pub vesting_cliff: [u8; 8], // i64, nothing is withdrawable before it
+    pub vesting_end: [u8; 8],   // i64, zeroed unless the raise vests linearly
+    pub beneficiary: [u8; 32],  // owner of every payout destination, the maker unless set at init
+    pub splits_count: [u8; 1],  // zeroed - the beneficiary gets the whole payout
+    pub splits: [PayoutSplit; MAX_PAYOUT_SPLITS],
+    pub fee_bps: [u8; 2],        // platform fee when the fundraiser was created
+    pub fee_recipient: [u8; 32], // owner of the fee token accounts
+    pub paused_at: [u8; 8],      // i64, zeroed unless the maker paused deposits
+    pub deadline_extension: [u8; 8], // seconds added to the deadline by pauses
+    pub creator: [u8; 32],       // maker at init - the pda seed, never changes
+    pub pending_maker: [u8; 32], // proposed maker until it accepts, zeroed if none
+    pub allowlist_root: [u8; 32], // merkle root of the wallets allowed to deposit, zeroed if open
+    pub attestor: [u8; 32],      // signs off-chain deposit attestations, zeroed if not required
+    pub tiers_count: [u8; 1],    // sorted by increasing min amount
+    pub tiers: [RewardTier; MAX_REWARD_TIERS],
+    pub receipt_mint: [u8; 32], // fungible receipts minted 1:1 with contributor amounts, zeroed if none
+    pub early_withdraw: [u8; 1], // contributors can pull out while the raise is open
+    pub early_withdraw_penalty_bps: [u8; 2], // share of an early withdrawal kept in the vault
+    pub pledge_mode: [u8; 1],   // contributors delegate their pledge, pulled by settle on success
+    pub pledged_amount: [u8; 8], // pledges counted in current_amount but not settled yet
+    pub succeeded: [u8; 1],     // goal met at the deadline, recorded by the first settle
+    pub round_distributed: [u8; 1], // the joined round pool was split, payouts can go through
+}
+
+// share of every payout sent to `recipient` token accounts
+#[repr(C)]
+#[derive(Pod, Zeroable, Clone, Copy)]
+pub struct PayoutSplit {
+    pub recipient: [u8; 32],
+    pub bps: [u8; 2],
+}
+
+// one entry of the accepted mints section appended right after `Fundraiser`
+#[repr(C)]
+#[derive(Pod, Zeroable, Clone, Copy)]
+pub struct AcceptedMint {
+    pub mint: [u8; 32],
+    pub vault: [u8; 32],
+    pub amount_to_raise: [u8; 8], // token units, zeroed in usd mode
+    pub current_amount: [u8; 8],  // token units, in usd mode too
+    pub price_feed: [u8; 32],     // zeroed unless the fundraiser is in usd mode
+    pub decimals: [u8; 1],
+    pub withdrawn_amount: [u8; 8], // vested tokens already withdrawn by the maker
+}
+
+impl AcceptedMint {
+    pub const LEN: usize = core::mem::size_of::<AcceptedMint>();
 }
 
 impl Fundraiser {
@@ -22,6 +100,118 @@ impl Fundraiser {
     }
     // bytemuck::cast_ref(self)
 
+    // unix time after which no more deposits are accepted
+    pub fn end_time(&self) -> u64 {
+        let fundraising_ends_days = u8::from_le_bytes(self.duration);
+        let unix_time_fundraising_started = u64::from_le_bytes(self.time_started);
+
+        u64::from(fundraising_ends_days) * 24 * 60 * 60
+            + unix_time_fundraising_started
+            + u64::from_le_bytes(self.deadline_extension)
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused_at != [0u8; 8]
+    }
+
+    // deadline passed and goal reached - funds can go to the maker
+    // once recorded, defaulted pledges can't undo the success
+    pub fn is_successful(&self, now: i64) -> bool {
+        self.end_time() <= now as u64
+            && (self.succeeded[0] == 1
+                || u64::from_le_bytes(self.current_amount)
+                    >= u64::from_le_bytes(self.amount_to_raise))
+    }
+
+    pub fn has_milestones(&self) -> bool {
+        self.milestones_count[0] > 0
+    }
+
+    pub fn is_aborted(&self) -> bool {
+        self.aborted[0] == 1
+    }
+
+    pub fn is_vesting(&self) -> bool {
+        self.vesting_end != [0u8; 8]
+    }
+
+    // linear share of `total` unlocked between the vesting cliff and end
+    pub fn vested(&self, total: u64, now: i64) -> u64 {
+        let cliff = i64::from_le_bytes(self.vesting_cliff);
+        let end = i64::from_le_bytes(self.vesting_end);
+
+        if now <= cliff {
+            return 0;
+        }
+        if now >= end {
+            return total;
+        }
+
+        (total as u128 * (now - cliff) as u128 / (end - cliff) as u128) as u64
+    }
+
+    pub fn payout_splits(&self) -> &[PayoutSplit] {
+        &self.splits[..self.splits_count[0] as usize]
+    }
+
+    pub fn has_fee(&self) -> bool {
+        u16::from_le_bytes(self.fee_bps) > 0
+    }
+
+    // token accounts each vault pays into - the fee one if any, then one per split
+    // or the beneficiary alone
+    pub fn payout_destinations_count(&self) -> usize {
+        self.has_fee() as usize + self.payout_splits().len().max(1)
+    }
+
+    pub fn is_vote_approved(&self) -> bool {
+        self.approver == [0u8; 32]
+    }
+
+    pub fn has_allowlist(&self) -> bool {
+        self.allowlist_root != [0u8; 32]
+    }
+
+    pub fn has_attestor(&self) -> bool {
+        self.attestor != [0u8; 32]
+    }
+
+    pub fn is_pledge_mode(&self) -> bool {
+        self.pledge_mode[0] == 1
+    }
+
+    pub fn allows_early_withdraw(&self) -> bool {
+        self.early_withdraw[0] == 1
+    }
+
+    pub fn has_receipt_mint(&self) -> bool {
+        self.receipt_mint != [0u8; 32]
+    }
+
+    pub fn reward_tiers(&self) -> &[RewardTier] {
+        &self.tiers[..self.tiers_count[0] as usize]
+    }
+
+    // highest tier a contributor total of `amount` qualifies for that still has receipts left
+    pub fn tier_for(&self, amount: u64) -> Option<usize> {
+        self.reward_tiers()
+            .iter()
+            .rposition(|tier| u64::from_le_bytes(tier.min_amount) <= amount && !tier.is_sold_out())
+    }
+
+    pub fn has_round(&self) -> bool {
+        self.round != [0u8; 32]
+    }
+
+    // the round share lands in the vault at distribution, nothing is paid out before
+    pub fn awaits_round(&self) -> bool {
+        self.has_round() && self.round_distributed[0] == 0
+    }
+
+    pub fn is_usd_mode(&self) -> bool {
+        self.goal_mode[0] == GOAL_MODE_USD
+    }
+
     pub fn min_sendable(&self) -> u64 {
         10_000_000 // 10 usdc
     }
@@ -29,4 +219,30 @@ impl Fundraiser {
     pub fn max_sendable(&self) -> u64 {
         10_000_000_000 // 10k usdc
     }
+
+    // full account size for a fundraiser accepting `mints_count` mints
+    pub fn space(mints_count: usize) -> usize {
+        Self::LEN + mints_count * AcceptedMint::LEN
+    }
+
+    pub fn accepted_mints(data: &[u8]) -> &[AcceptedMint] {
+        let header = bytemuck::from_bytes::<Fundraiser>(&data[..Self::LEN]);
+        let count = header.accepted_mints_count[0] as usize;
+
+        bytemuck::cast_slice(&data[Self::LEN..Self::space(count)])
+    }
+
+    pub fn accepted_mints_mut(data: &mut [u8]) -> &mut [AcceptedMint] {
+        let count =
+            bytemuck::from_bytes::<Fundraiser>(&data[..Self::LEN]).accepted_mints_count[0] as usize;
+
+        bytemuck::cast_slice_mut(&mut data[Self::LEN..Self::space(count)])
+    }
+
+    // index of `mint` in the accepted mints section, if the fundraiser accepts it
+    pub fn find_accepted_mint(data: &[u8], mint: &Address) -> Option<usize> {
+        Self::accepted_mints(data)
+            .iter()
+            .position(|accepted| mint.as_ref() == accepted.mint.as_ref())
+    }
 }
@@ -0,0 +1,30 @@
+use bytemuck::{Pod, Zeroable};
+
+// sponsor matching pool attached to a fundraiser - seeds ["matching", fundraiser]
+#[repr(C)]
+#[derive(Pod, Zeroable, Clone, Copy)]
+pub struct Matching {
+    pub sponsor: [u8; 32],
+    pub fundraiser: [u8; 32],
+    pub matching_vault: [u8; 32], // ata of this pda for the fundraiser primary mint
+    pub ratio_bps: [u8; 2],       // 10_000 = 1:1
+    pub cap: [u8; 8],             // max the sponsor is willing to match
+    pub matched_amount: [u8; 8],
+    pub bump: [u8; 1],
+}
+
+impl Matching {
+    pub const LEN: usize = core::mem::size_of::<Matching>();
+
+    pub fn to_bytes(&self) -> &[u8; Self::LEN] {
+        bytemuck::bytes_of(self).try_into().unwrap()
+    }
+
+    // amount matched for a deposit of `amount`, bounded by what is left of the cap
+    pub fn match_for(&self, amount: u64) -> u64 {
+        let matched = amount as u128 * u16::from_le_bytes(self.ratio_bps) as u128 / 10_000;
+        let left = u64::from_le_bytes(self.cap) - u64::from_le_bytes(self.matched_amount);
+
+        (matched as u64).min(left)
+    }
+}
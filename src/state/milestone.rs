@@ -0,0 +1,28 @@
+use bytemuck::{Pod, Zeroable};
+
+// max milestones a fundraiser can split its payout into
+pub const MAX_MILESTONES: usize = 8;
+
+pub const MILESTONE_PENDING: u8 = 0;
+pub const MILESTONE_SUBMITTED: u8 = 1; // maker says the work is done
+pub const MILESTONE_APPROVED: u8 = 2;
+pub const MILESTONE_RELEASED: u8 = 3; // tranche paid out
+pub const MILESTONE_FROZEN: u8 = 4; // rejected, tranche stays in the vault
+
+#[repr(C)]
+#[derive(Pod, Zeroable, Clone, Copy)]
+pub struct Milestone {
+    pub bps: [u8; 2], // share of the raised amount released with this milestone
+    pub status: [u8; 1],
+    pub submitted_at: [u8; 8],   // i64, opens the contributor vote
+    pub approve_weight: [u8; 8], // sum of contributor amounts voting approve
+    pub reject_weight: [u8; 8],
+}
+
+impl Milestone {
+    pub const LEN: usize = core::mem::size_of::<Milestone>();
+
+    pub fn is_settled(&self) -> bool {
+        self.status[0] == MILESTONE_RELEASED || self.status[0] == MILESTONE_FROZEN
+    }
+}
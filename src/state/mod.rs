@@ -3,3 +3,36 @@ pub use fundraiser::*;
 
 pub mod contributor;
 pub use contributor::*;
+
+pub mod price_feed;
+pub use price_feed::*;
+
+pub mod matching;
+pub use matching::*;
+
+pub mod round;
+pub use round::*;
+
+pub mod milestone;
+pub use milestone::*;
+
+pub mod vote;
+pub use vote::*;
+
+pub mod proposal;
+pub use proposal::*;
+
+pub mod config;
+pub use config::*;
+
+pub mod multisig;
+pub use multisig::*;
+
+pub mod allowlist;
+pub use allowlist::*;
+
+pub mod denylist;
+pub use denylist::*;
+
+pub mod reward_tier;
+pub use reward_tier::*;
@@ -0,0 +1,27 @@
+use bytemuck::{Pod, Zeroable};
+
+// max members of a multisig maker
+pub const MAX_MULTISIG_SIGNERS: usize = 8;
+
+// M-of-N signer set standing in as the fundraiser maker - seeds ["multisig", fundraiser]
+#[repr(C)]
+#[derive(Pod, Zeroable, Clone, Copy)]
+pub struct Multisig {
+    pub fundraiser: [u8; 32],
+    pub threshold: [u8; 1], // signatures needed among the members
+    pub signers_count: [u8; 1],
+    pub signers: [[u8; 32]; MAX_MULTISIG_SIGNERS],
+    pub bump: [u8; 1],
+}
+
+impl Multisig {
+    pub const LEN: usize = core::mem::size_of::<Multisig>();
+
+    pub fn to_bytes(&self) -> &[u8; Self::LEN] {
+        bytemuck::bytes_of(self).try_into().unwrap()
+    }
+
+    pub fn signers(&self) -> &[[u8; 32]] {
+        &self.signers[..self.signers_count[0] as usize]
+    }
+}
@@ -0,0 +1,50 @@
+use bytemuck::{Pod, Zeroable};
+use pinocchio::error::ProgramError;
+
+// same magic number pyth writes at the start of its price accounts
+pub const PRICE_FEED_MAGIC: u32 = 0xa1b2c3d4;
+
+// usd amounts (goal / raised) are stored with 6 decimals, like usdc
+pub const USD_DECIMALS: i32 = 6;
+
+// pyth-style price account - only the fields we read, in the same order
+#[repr(C)]
+#[derive(Pod, Zeroable, Clone, Copy)]
+pub struct PriceFeed {
+    pub magic: [u8; 4],
+    pub expo: [u8; 4],         // i32, price = price * 10^expo
+    pub price: [u8; 8],        // i64
+    pub conf: [u8; 8],         // u64, same exponent as price
+    pub publish_time: [u8; 8], // i64 unix timestamp
+}
+
+impl PriceFeed {
+    pub const LEN: usize = core::mem::size_of::<PriceFeed>();
+
+    pub fn to_bytes(&self) -> &[u8; Self::LEN] {
+        bytemuck::bytes_of(self).try_into().unwrap()
+    }
+
+    // converts `amount` of a token with `decimals` into usd (USD_DECIMALS)
+    pub fn to_usd(&self, amount: u64, decimals: u8) -> Result<u64, ProgramError> {
+        let price = i64::from_le_bytes(self.price) as u128;
+        let exponent = USD_DECIMALS + i32::from_le_bytes(self.expo) - decimals as i32;
+
+        let value = amount as u128 * price;
+        let value = if exponent >= 0 {
+            10u128
+                .checked_pow(exponent as u32)
+                .and_then(|scale| value.checked_mul(scale))
+        } else {
+            Some(
+                10u128
+                    .checked_pow(exponent.unsigned_abs())
+                    .map_or(0, |scale| value / scale),
+            )
+        };
+
+        value
+            .and_then(|value| u64::try_from(value).ok())
+            .ok_or(ProgramError::ArithmeticOverflow)
+    }
+}
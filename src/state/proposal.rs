@@ -0,0 +1,39 @@
+use bytemuck::{Pod, Zeroable};
+
+use crate::state::MAX_ACCEPTED_MINTS;
+
+// abort proposals stay open this long, in seconds
+pub const ABORT_VOTE_DURATION: i64 = 7 * 24 * 60 * 60;
+// share of the total contributed weight that must vote abort
+pub const ABORT_SUPERMAJORITY_BPS: u128 = 6_667;
+// milestone slot of the vote pda when voting on the abort proposal - the proposal nonce follows it
+pub const ABORT_VOTE: u8 = u8::MAX;
+
+pub const PROPOSAL_OPEN: u8 = 0;
+pub const PROPOSAL_PASSED: u8 = 1; // vault remainder refundable pro-rata
+pub const PROPOSAL_REJECTED: u8 = 2;
+
+// kill switch proposal, one open at a time per fundraiser - seeds ["proposal", fundraiser]
+// a rejected proposal is reopened in place with the next nonce
+#[repr(C)]
+#[derive(Pod, Zeroable, Clone, Copy)]
+pub struct Proposal {
+    pub fundraiser: [u8; 32],
+    pub proposer: [u8; 32],
+    pub ends_at: [u8; 8], // i64
+    pub abort_weight: [u8; 8],
+    pub keep_weight: [u8; 8],
+    pub total_weight: [u8; 8], // fundraiser contributed amount when finalized
+    pub remaining: [[u8; 8]; MAX_ACCEPTED_MINTS], // vault balances when finalized, accepted mints order
+    pub nonce: [u8; 8], // proposals rejected before this one, seeds its votes
+    pub status: [u8; 1],
+    pub bump: [u8; 1],
+}
+
+impl Proposal {
+    pub const LEN: usize = core::mem::size_of::<Proposal>();
+
+    pub fn to_bytes(&self) -> &[u8; Self::LEN] {
+        bytemuck::bytes_of(self).try_into().unwrap()
+    }
+}
@@ -0,0 +1,27 @@
+use bytemuck::{Pod, Zeroable};
+
+// max reward tiers a fundraiser can offer
+pub const MAX_REWARD_TIERS: usize = 4;
+
+// metadata uri bytes, zero padded
+pub const MAX_TIER_URI_LEN: usize = 96;
+
+// perk unlocked once a contributor total reaches `min_amount`, proven by a receipt token
+// minted from the tier mint - seeds ["tier", fundraiser, index]
+#[repr(C)]
+#[derive(Pod, Zeroable, Clone, Copy)]
+pub struct RewardTier {
+    pub min_amount: [u8; 8], // same unit as the contributor amount
+    pub supply: [u8; 4],     // max receipts, zeroed if unlimited
+    pub minted: [u8; 4],
+    pub uri: [u8; MAX_TIER_URI_LEN],
+}
+
+impl RewardTier {
+    pub const LEN: usize = core::mem::size_of::<RewardTier>();
+
+    pub fn is_sold_out(&self) -> bool {
+        let supply = u32::from_le_bytes(self.supply);
+        supply != 0 && u32::from_le_bytes(self.minted) >= supply
+    }
+}
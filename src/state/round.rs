@@ -0,0 +1,61 @@
+use bytemuck::{Pod, Zeroable};
+
+// max fundraisers a single quadratic funding round can group
+pub const MAX_ROUND_PROJECTS: usize = 8;
+
+// quadratic funding round - seeds ["round", creator]
+#[repr(C)]
+#[derive(Pod, Zeroable, Clone, Copy)]
+pub struct Round {
+    pub creator: [u8; 32],
+    pub mint: [u8; 32],
+    pub pool_vault: [u8; 32], // ata of this pda holding the matching pool
+    pub end_time: [u8; 8],    // i64 unix timestamp
+    pub distributed: [u8; 1],
+    pub bump: [u8; 1],
+    pub projects_count: [u8; 1],
+    pub projects: [RoundProject; MAX_ROUND_PROJECTS],
+}
+
+#[repr(C)]
+#[derive(Pod, Zeroable, Clone, Copy)]
+pub struct RoundProject {
+    pub fundraiser: [u8; 32],
+    pub vault: [u8; 32],
+    pub sum_sqrt: [u8; 8], // sum of sqrt(contributor amount) over every contributor
+}
+
+impl Round {
+    pub const LEN: usize = core::mem::size_of::<Round>();
+
+    pub fn to_bytes(&self) -> &[u8; Self::LEN] {
+        bytemuck::bytes_of(self).try_into().unwrap()
+    }
+
+    pub fn projects(&self) -> &[RoundProject] {
+        &self.projects[..self.projects_count[0] as usize]
+    }
+
+    pub fn find_project(&self, fundraiser: &[u8]) -> Option<usize> {
+        self.projects()
+            .iter()
+            .position(|project| project.fundraiser.as_ref() == fundraiser)
+    }
+}
+
+// floor(sqrt(value)) - newton iteration
+pub fn integer_sqrt(value: u64) -> u64 {
+    if value < 2 {
+        return value;
+    }
+
+    let mut current = value / 2 + 1;
+    let mut next = (current + value / current) / 2;
+
+    while next < current {
+        current = next;
+        next = (current + value / current) / 2;
+    }
+
+    current
+}
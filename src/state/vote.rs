@@ -0,0 +1,21 @@
+use bytemuck::{Pod, Zeroable};
+
+// one contributor vote on one milestone - seeds ["vote", fundraiser, [milestone], contributor]
+// abort votes use the ABORT_VOTE slot followed by the proposal nonce
+#[repr(C)]
+#[derive(Pod, Zeroable, Clone, Copy)]
+pub struct Vote {
+    pub fundraiser: [u8; 32],
+    pub contributor: [u8; 32],
+    pub milestone: [u8; 1],
+    pub approve: [u8; 1],
+    pub weight: [u8; 8], // contributor amount at the time of the vote
+}
+
+impl Vote {
+    pub const LEN: usize = core::mem::size_of::<Vote>();
+
+    pub fn to_bytes(&self) -> &[u8; Self::LEN] {
+        bytemuck::bytes_of(self).try_into().unwrap()
+    }
+}
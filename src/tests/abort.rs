@@ -0,0 +1,296 @@
+#[cfg(test)]
+pub mod abort {
+    use core::fmt::Error;
+
+    use crate::instructions::FundraiserInstruction;
+    use crate::state::{
+        Fundraiser, Proposal, ABORT_VOTE, ABORT_VOTE_DURATION, PROPOSAL_OPEN, PROPOSAL_PASSED,
+        PROPOSAL_REJECTED,
+    };
+    use crate::tests::tests::{program_id, token_balance, warp_forward, ReusableState};
+    use litesvm::LiteSVM;
+    use solana_instruction::{AccountMeta, Instruction};
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_pubkey::Pubkey;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    // the only contributor proposes and votes to abort, then finalizes once the window closed
+    pub fn abort_fundraiser_function(
+        svm: &mut LiteSVM,
+        state: &ReusableState,
+        contributor: &Keypair,
+    ) -> Result<Pubkey, Error> {
+        let ReusableState {
+            fundraiser,
+            vault,
+            system_program,
+            ..
+        } = state;
+
+        let contributor_pda = Pubkey::find_program_address(
+            &[
+                b"contributor".as_ref(),
+                fundraiser.0.as_ref(),
+                contributor.pubkey().as_ref(),
+            ],
+            &program_id(),
+        );
+        let proposal =
+            Pubkey::find_program_address(&[b"proposal", fundraiser.0.as_ref()], &program_id()).0;
+        let vote = Pubkey::find_program_address(
+            &[
+                b"vote".as_ref(),
+                fundraiser.0.as_ref(),
+                &[ABORT_VOTE],
+                contributor.pubkey().as_ref(),
+                &0u64.to_le_bytes(),
+            ],
+            &program_id(),
+        );
+
+        let propose_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(contributor.pubkey(), true),
+                AccountMeta::new(fundraiser.0, false),
+                AccountMeta::new(contributor_pda.0, false),
+                AccountMeta::new(proposal, false),
+                AccountMeta::new(*system_program, false),
+            ],
+            data: vec![FundraiserInstruction::ProposeAbort as u8],
+        };
+
+        let vote_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(contributor.pubkey(), true),
+                AccountMeta::new(fundraiser.0, false),
+                AccountMeta::new(contributor_pda.0, false),
+                AccountMeta::new(proposal, false),
+                AccountMeta::new(vote.0, false),
+                AccountMeta::new(*system_program, false),
+            ],
+            data: vec![FundraiserInstruction::VoteAbort as u8, 1],
+        };
+
+        let message = Message::new(&[propose_ix, vote_ix], Some(&contributor.pubkey()));
+        let recent_blockhash = svm.latest_blockhash();
+
+        let transaction = Transaction::new(&[contributor], message, recent_blockhash);
+
+        let tx = svm.send_transaction(transaction).unwrap();
+
+        println!("\nPropose and vote abort transaction sucessfull");
+        println!("CUs Consumed: {}", tx.compute_units_consumed);
+
+        warp_forward(svm, ABORT_VOTE_DURATION);
+
+        let finalize_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(fundraiser.0, false),
+                AccountMeta::new(proposal, false),
+                AccountMeta::new_readonly(*vault, false),
+            ],
+            data: vec![FundraiserInstruction::FinalizeAbort as u8],
+        };
+
+        let message = Message::new(&[finalize_ix], Some(&contributor.pubkey()));
+        let recent_blockhash = svm.latest_blockhash();
+
+        let transaction = Transaction::new(&[contributor], message, recent_blockhash);
+
+        let tx = svm.send_transaction(transaction).unwrap();
+
+        println!("\nFinalize abort transaction sucessfull");
+        println!("CUs Consumed: {}", tx.compute_units_consumed);
+
+        let proposal_account = svm.get_account(&proposal).unwrap();
+        let proposal_state = bytemuck::from_bytes::<Proposal>(&proposal_account.data);
+        assert_eq!(proposal_state.status, [PROPOSAL_PASSED]);
+        assert_eq!(u64::from_le_bytes(proposal_state.remaining[0]), 100_000_000);
+
+        let fundraiser_account = svm.get_account(&fundraiser.0).unwrap();
+        let fundraiser_state =
+            bytemuck::from_bytes::<Fundraiser>(&fundraiser_account.data[..Fundraiser::LEN]);
+        assert!(fundraiser_state.is_aborted());
+
+        Ok(proposal)
+    }
+
+    pub fn claim_remainder_function(
+        svm: &mut LiteSVM,
+        state: &ReusableState,
+        contributor: &Keypair,
+        proposal: &Pubkey,
+    ) -> Result<(), Error> {
+        let ReusableState {
+            fundraiser,
+            mint,
+            vault,
+            token_program,
+            config,
+            ..
+        } = state;
+
+        let contributor_pda = Pubkey::find_program_address(
+            &[
+                b"contributor".as_ref(),
+                fundraiser.0.as_ref(),
+                contributor.pubkey().as_ref(),
+            ],
+            &program_id(),
+        );
+        let contributor_ata =
+            spl_associated_token_account::get_associated_token_address(&contributor.pubkey(), mint);
+
+        let claim_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(contributor.pubkey(), true),
+                AccountMeta::new(fundraiser.0, false),
+                AccountMeta::new(contributor_pda.0, false),
+                AccountMeta::new_readonly(*proposal, false),
+                AccountMeta::new_readonly(*token_program, false),
+                AccountMeta::new_readonly(*config, false),
+                AccountMeta::new(*vault, false),
+                AccountMeta::new(contributor_ata, false),
+            ],
+            data: vec![FundraiserInstruction::ClaimRemainder as u8],
+        };
+
+        let message = Message::new(&[claim_ix], Some(&contributor.pubkey()));
+        let recent_blockhash = svm.latest_blockhash();
+
+        let transaction = Transaction::new(&[contributor], message, recent_blockhash);
+
+        let tx = svm.send_transaction(transaction).unwrap();
+
+        println!("\nClaim remainder transaction sucessfull");
+        println!("CUs Consumed: {}", tx.compute_units_consumed);
+
+        // sole contributor gets the whole unreleased balance back
+        assert_eq!(token_balance(svm, &contributor_ata), 1_000_000_000);
+        assert_eq!(token_balance(svm, vault), 0);
+
+        Ok(())
+    }
+
+    // the contributor votes a first proposal down, then proposes again - the reopened proposal
+    // starts from zero and takes votes under the next nonce
+    pub fn repropose_abort_function(
+        svm: &mut LiteSVM,
+        state: &ReusableState,
+        contributor: &Keypair,
+    ) -> Result<(), Error> {
+        let ReusableState {
+            fundraiser,
+            vault,
+            system_program,
+            ..
+        } = state;
+
+        let contributor_pda = Pubkey::find_program_address(
+            &[
+                b"contributor".as_ref(),
+                fundraiser.0.as_ref(),
+                contributor.pubkey().as_ref(),
+            ],
+            &program_id(),
+        );
+        let proposal =
+            Pubkey::find_program_address(&[b"proposal", fundraiser.0.as_ref()], &program_id()).0;
+        let vote = |nonce: u64| {
+            Pubkey::find_program_address(
+                &[
+                    b"vote".as_ref(),
+                    fundraiser.0.as_ref(),
+                    &[ABORT_VOTE],
+                    contributor.pubkey().as_ref(),
+                    &nonce.to_le_bytes(),
+                ],
+                &program_id(),
+            )
+            .0
+        };
+
+        let propose_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(contributor.pubkey(), true),
+                AccountMeta::new(fundraiser.0, false),
+                AccountMeta::new(contributor_pda.0, false),
+                AccountMeta::new(proposal, false),
+                AccountMeta::new(*system_program, false),
+            ],
+            data: vec![FundraiserInstruction::ProposeAbort as u8],
+        };
+
+        let vote_ix = |nonce: u64, abort: u8| Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(contributor.pubkey(), true),
+                AccountMeta::new(fundraiser.0, false),
+                AccountMeta::new(contributor_pda.0, false),
+                AccountMeta::new(proposal, false),
+                AccountMeta::new(vote(nonce), false),
+                AccountMeta::new(*system_program, false),
+            ],
+            data: vec![FundraiserInstruction::VoteAbort as u8, abort],
+        };
+
+        let finalize_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(fundraiser.0, false),
+                AccountMeta::new(proposal, false),
+                AccountMeta::new_readonly(*vault, false),
+            ],
+            data: vec![FundraiserInstruction::FinalizeAbort as u8],
+        };
+
+        let message = Message::new(
+            &[propose_ix.clone(), vote_ix(0, 0)],
+            Some(&contributor.pubkey()),
+        );
+        let recent_blockhash = svm.latest_blockhash();
+
+        let transaction = Transaction::new(&[contributor], message, recent_blockhash);
+
+        svm.send_transaction(transaction).unwrap();
+
+        warp_forward(svm, ABORT_VOTE_DURATION);
+
+        let message = Message::new(&[finalize_ix], Some(&contributor.pubkey()));
+        let recent_blockhash = svm.latest_blockhash();
+
+        let transaction = Transaction::new(&[contributor], message, recent_blockhash);
+
+        svm.send_transaction(transaction).unwrap();
+
+        let proposal_account = svm.get_account(&proposal).unwrap();
+        let proposal_state = bytemuck::from_bytes::<Proposal>(&proposal_account.data);
+        assert_eq!(proposal_state.status, [PROPOSAL_REJECTED]);
+
+        let message = Message::new(&[propose_ix, vote_ix(1, 1)], Some(&contributor.pubkey()));
+        let recent_blockhash = svm.latest_blockhash();
+
+        let transaction = Transaction::new(&[contributor], message, recent_blockhash);
+
+        let tx = svm.send_transaction(transaction).unwrap();
+
+        println!("\nRepropose and vote abort transaction sucessfull");
+        println!("CUs Consumed: {}", tx.compute_units_consumed);
+
+        let proposal_account = svm.get_account(&proposal).unwrap();
+        let proposal_state = bytemuck::from_bytes::<Proposal>(&proposal_account.data);
+        assert_eq!(proposal_state.status, [PROPOSAL_OPEN]);
+        assert_eq!(u64::from_le_bytes(proposal_state.nonce), 1);
+        assert_eq!(u64::from_le_bytes(proposal_state.keep_weight), 0);
+        assert_eq!(u64::from_le_bytes(proposal_state.abort_weight), 100_000_000);
+
+        Ok(())
+    }
+}
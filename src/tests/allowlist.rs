@@ -0,0 +1,183 @@
+#[cfg(test)]
+pub mod allowlist {
+    use core::fmt::Error;
+
+    use bytemuck::Zeroable;
+
+    use crate::instructions::{ContributeData, FundraiserInstruction, InitData};
+    use crate::state::{allowlist_leaf, allowlist_node, Contributor, GOAL_MODE_TOKEN};
+    use crate::tests::tests::{program_id, ReusableState};
+    use litesvm::LiteSVM;
+    use litesvm_token::{CreateAssociatedTokenAccount, MintTo};
+    use solana_instruction::{AccountMeta, Instruction};
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_native_token::LAMPORTS_PER_SOL;
+    use solana_pubkey::Pubkey;
+    use solana_sdk_ids::sysvar::rent;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    pub const ALLOWLIST_CAP: u64 = 150_000_000; // 150 usdc
+
+    // private raise allowing the returned contributor up to 150 usdc and one other wallet,
+    // returns (contributor, proof)
+    pub fn create_allowlist_fundraiser_function(
+        svm: &mut LiteSVM,
+        state: &ReusableState,
+    ) -> Result<(Keypair, [u8; 32]), Error> {
+        let ReusableState {
+            maker,
+            fundraiser,
+            mint,
+            vault,
+            system_program,
+            token_program,
+            ata_program,
+            config,
+            ..
+        } = state;
+
+        let contributor = Keypair::new();
+
+        let contributor_leaf = allowlist_leaf(contributor.pubkey().as_ref(), ALLOWLIST_CAP);
+        let other_leaf = allowlist_leaf(Pubkey::new_unique().as_ref(), 0);
+
+        let initialize_data = InitData {
+            amount_to_raise: 10_000_000_000u64.to_le_bytes(),
+            duration: 3u8.to_le_bytes(),
+            goal_mode: [GOAL_MODE_TOKEN],
+            allowlist_root: allowlist_node(&contributor_leaf, &other_leaf),
+            ..Zeroable::zeroed()
+        };
+
+        let make_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new(*mint, false),
+                AccountMeta::new(fundraiser.0, false),
+                AccountMeta::new(*vault, false),
+                AccountMeta::new(*system_program, false),
+                AccountMeta::new(*token_program, false),
+                AccountMeta::new(*ata_program, false),
+                AccountMeta::new(rent::ID, false),
+                AccountMeta::new_readonly(*config, false),
+            ],
+            data: [
+                (FundraiserInstruction::Initialize as u8)
+                    .to_le_bytes()
+                    .to_vec(),
+                initialize_data.to_bytes().to_vec(),
+            ]
+            .concat(),
+        };
+
+        let message = Message::new(&[make_ix], Some(&maker.pubkey()));
+        let recent_blockhash = svm.latest_blockhash();
+
+        let transaction = Transaction::new(&[&maker], message, recent_blockhash);
+
+        let tx = svm.send_transaction(transaction).unwrap();
+
+        println!("\nAllowlist initialize transaction sucessfull");
+        println!("CUs Consumed: {}", tx.compute_units_consumed);
+
+        Ok((contributor, other_leaf))
+    }
+
+    // 100 usdc goes through, another 100 would exceed the 150 usdc cap
+    pub fn donate_allowlisted_function(
+        svm: &mut LiteSVM,
+        state: &ReusableState,
+        contributor: &Keypair,
+        proof: &[u8; 32],
+    ) -> Result<(), Error> {
+        let ReusableState {
+            maker,
+            fundraiser,
+            mint,
+            vault,
+            system_program,
+            token_program,
+            ata_program,
+            config,
+            ..
+        } = state;
+
+        svm.airdrop(&contributor.pubkey(), 10 * LAMPORTS_PER_SOL)
+            .expect("Airdrop failed");
+
+        let contributor_ata = CreateAssociatedTokenAccount::new(svm, contributor, mint)
+            .owner(&contributor.pubkey())
+            .send()
+            .unwrap();
+
+        MintTo::new(svm, maker, mint, &contributor_ata, 1_000_000_000)
+            .send()
+            .unwrap();
+
+        let contributor_pda = Pubkey::find_program_address(
+            &[
+                b"contributor".as_ref(),
+                fundraiser.0.as_ref(),
+                contributor.pubkey().as_ref(),
+            ],
+            &program_id(),
+        );
+
+        let contribute_data = ContributeData {
+            amount: 100_000_000u64.to_le_bytes(),
+            min_out: 0u64.to_le_bytes(),
+            swap: [0],
+            swap_accounts: [0],
+        };
+
+        let donate_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(contributor.pubkey(), true),
+                AccountMeta::new(*mint, false),
+                AccountMeta::new(fundraiser.0, false),
+                AccountMeta::new(contributor_pda.0, false),
+                AccountMeta::new(contributor_ata, false),
+                AccountMeta::new(*vault, false),
+                AccountMeta::new(*system_program, false),
+                AccountMeta::new(*token_program, false),
+                AccountMeta::new(*ata_program, false),
+                AccountMeta::new(rent::ID, false),
+                AccountMeta::new_readonly(*config, false),
+            ],
+            data: [
+                (FundraiserInstruction::Deposit as u8)
+                    .to_le_bytes()
+                    .to_vec(),
+                contribute_data.to_bytes().to_vec(),
+                ALLOWLIST_CAP.to_le_bytes().to_vec(),
+                proof.to_vec(),
+            ]
+            .concat(),
+        };
+
+        let message = Message::new(&[donate_ix.clone()], Some(&contributor.pubkey()));
+        let recent_blockhash = svm.latest_blockhash();
+
+        let transaction = Transaction::new(&[contributor], message, recent_blockhash);
+
+        let tx = svm.send_transaction(transaction).unwrap();
+
+        println!("\nAllowlisted donate transaction sucessfull");
+        println!("CUs Consumed: {}", tx.compute_units_consumed);
+
+        let contributor_account = svm.get_account(&contributor_pda.0).unwrap();
+        let contributor_state = bytemuck::from_bytes::<Contributor>(&contributor_account.data);
+        assert_eq!(u64::from_le_bytes(contributor_state.amount), 100_000_000);
+
+        svm.expire_blockhash();
+        let message = Message::new(&[donate_ix], Some(&contributor.pubkey()));
+        let transaction = Transaction::new(&[contributor], message, svm.latest_blockhash());
+        assert!(svm.send_transaction(transaction).is_err());
+
+        Ok(())
+    }
+}
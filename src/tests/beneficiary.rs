@@ -0,0 +1,62 @@
+#[cfg(test)]
+pub mod beneficiary {
+    use core::fmt::Error;
+
+    use crate::instructions::FundraiserInstruction;
+    use crate::state::Fundraiser;
+    use crate::tests::tests::{program_id, ReusableState};
+    use litesvm::LiteSVM;
+    use solana_instruction::{AccountMeta, Instruction};
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    // maker is the default beneficiary and hands it to a charity wallet, returns the charity
+    pub fn set_beneficiary_function(
+        svm: &mut LiteSVM,
+        state: &ReusableState,
+    ) -> Result<Keypair, Error> {
+        let ReusableState {
+            maker, fundraiser, ..
+        } = state;
+
+        let charity = Keypair::new();
+
+        let set_beneficiary_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new_readonly(maker.pubkey(), true),
+                AccountMeta::new(fundraiser.0, false),
+                AccountMeta::new_readonly(charity.pubkey(), false),
+            ],
+            data: vec![FundraiserInstruction::SetBeneficiary as u8],
+        };
+
+        let message = Message::new(&[set_beneficiary_ix.clone()], Some(&maker.pubkey()));
+        let recent_blockhash = svm.latest_blockhash();
+
+        let transaction = Transaction::new(&[&maker], message, recent_blockhash);
+
+        let tx = svm.send_transaction(transaction).unwrap();
+
+        println!("\nSet beneficiary transaction sucessfull");
+        println!("CUs Consumed: {}", tx.compute_units_consumed);
+
+        let fundraiser_account = svm.get_account(&fundraiser.0).unwrap();
+        let fundraiser_state =
+            bytemuck::from_bytes::<Fundraiser>(&fundraiser_account.data[..Fundraiser::LEN]);
+        assert_eq!(fundraiser_state.beneficiary, charity.pubkey().to_bytes());
+
+        // the maker is no longer the beneficiary and can't change it back
+        svm.expire_blockhash();
+        let message = Message::new(&[set_beneficiary_ix], Some(&maker.pubkey()));
+        let recent_blockhash = svm.latest_blockhash();
+
+        let transaction = Transaction::new(&[&maker], message, recent_blockhash);
+
+        assert!(svm.send_transaction(transaction).is_err());
+
+        Ok(charity)
+    }
+}
@@ -2,7 +2,10 @@
 pub mod create_fundraiser {
     use core::fmt::Error;
 
+    use bytemuck::Zeroable;
+
     use crate::instructions::{FundraiserInstruction, InitData};
+    use crate::state::GOAL_MODE_TOKEN;
     use crate::tests::tests::{program_id, ReusableState};
     use litesvm::LiteSVM;
     use solana_instruction::{AccountMeta, Instruction};
@@ -23,6 +26,7 @@ pub mod create_fundraiser {
             system_program,
             token_program,
             ata_program,
+            config,
             ..
         } = state;
 
@@ -47,8 +51,12 @@ pub mod create_fundraiser {
         let initialize_data = InitData {
             amount_to_raise: amount_to_raise.to_le_bytes(),
             duration: 3u8.to_le_bytes(),
+            goal_mode: [GOAL_MODE_TOKEN],
+            max_price_age: 0u64.to_le_bytes(),
+            max_conf_bps: 0u16.to_le_bytes(),
             max_amount_sendable: max_amount_to_donate.to_le_bytes(),
             min_amount_sendable: min_amount_to_donate.to_le_bytes(),
+            ..Zeroable::zeroed()
         };
 
         let make_ix = Instruction {
@@ -62,6 +70,7 @@ pub mod create_fundraiser {
                 AccountMeta::new(*token_program, false),
                 AccountMeta::new(*ata_program, false),
                 AccountMeta::new(rent_sysvar, false),
+                AccountMeta::new_readonly(*config, false),
             ],
             data: [
                 (FundraiserInstruction::Initialize as u8)
@@ -0,0 +1,249 @@
+#[cfg(test)]
+pub mod denylist {
+    use core::fmt::Error;
+
+    use crate::instructions::{ContributeData, FundraiserInstruction};
+    use crate::state::{Config, Contributor, Denylist, DENYLIST_ADD};
+    use crate::tests::tests::{program_id, ReusableState};
+    use litesvm::LiteSVM;
+    use litesvm_token::{CreateAssociatedTokenAccount, MintTo};
+    use solana_instruction::{AccountMeta, Instruction};
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_native_token::LAMPORTS_PER_SOL;
+    use solana_pubkey::Pubkey;
+    use solana_sdk_ids::sysvar::rent;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    // admin creates the denylist and adds a wallet, returns the denylist and the wallet
+    pub fn init_denylist_function(
+        svm: &mut LiteSVM,
+        state: &ReusableState,
+    ) -> Result<(Pubkey, Keypair), Error> {
+        let ReusableState {
+            maker,
+            config,
+            system_program,
+            ..
+        } = state;
+
+        let denylist = Pubkey::find_program_address(&[b"denylist"], &program_id()).0;
+        let sanctioned = Keypair::new();
+
+        let init_denylist_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new(*config, false),
+                AccountMeta::new(denylist, false),
+                AccountMeta::new_readonly(*system_program, false),
+            ],
+            data: vec![FundraiserInstruction::InitDenylist as u8],
+        };
+
+        let update_denylist_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new_readonly(maker.pubkey(), true),
+                AccountMeta::new_readonly(*config, false),
+                AccountMeta::new(denylist, false),
+            ],
+            data: [
+                vec![FundraiserInstruction::UpdateDenylist as u8, DENYLIST_ADD],
+                sanctioned.pubkey().to_bytes().to_vec(),
+            ]
+            .concat(),
+        };
+
+        let message = Message::new(
+            &[init_denylist_ix, update_denylist_ix],
+            Some(&maker.pubkey()),
+        );
+        let recent_blockhash = svm.latest_blockhash();
+
+        let transaction = Transaction::new(&[&maker], message, recent_blockhash);
+
+        let tx = svm.send_transaction(transaction).unwrap();
+
+        println!("\nInit denylist transaction sucessfull");
+        println!("CUs Consumed: {}", tx.compute_units_consumed);
+
+        let config_account = svm.get_account(config).unwrap();
+        let config_state = bytemuck::from_bytes::<Config>(&config_account.data);
+        assert!(config_state.has_denylist());
+
+        let denylist_account = svm.get_account(&denylist).unwrap();
+        let denylist_state = bytemuck::from_bytes::<Denylist>(&denylist_account.data);
+        assert!(denylist_state.contains(sanctioned.pubkey().as_ref()));
+
+        Ok((denylist, sanctioned))
+    }
+
+    // denylisted wallets can't deposit, everyone else passes the denylist along
+    pub fn donate_with_denylist_function(
+        svm: &mut LiteSVM,
+        state: &ReusableState,
+        denylist: &Pubkey,
+        sanctioned: &Keypair,
+    ) -> Result<(), Error> {
+        let ReusableState {
+            maker,
+            fundraiser,
+            mint,
+            vault,
+            system_program,
+            token_program,
+            ata_program,
+            config,
+            ..
+        } = state;
+
+        let contributor = Keypair::new();
+
+        let deposit = |svm: &mut LiteSVM, contributor: &Keypair| {
+            svm.airdrop(&contributor.pubkey(), 10 * LAMPORTS_PER_SOL)
+                .expect("Airdrop failed");
+
+            let contributor_ata = CreateAssociatedTokenAccount::new(svm, contributor, mint)
+                .owner(&contributor.pubkey())
+                .send()
+                .unwrap();
+
+            MintTo::new(svm, maker, mint, &contributor_ata, 1_000_000_000)
+                .send()
+                .unwrap();
+
+            let contributor_pda = Pubkey::find_program_address(
+                &[
+                    b"contributor".as_ref(),
+                    fundraiser.0.as_ref(),
+                    contributor.pubkey().as_ref(),
+                ],
+                &program_id(),
+            );
+
+            let contribute_data = ContributeData {
+                amount: 100_000_000u64.to_le_bytes(),
+                min_out: 0u64.to_le_bytes(),
+                swap: [0],
+                swap_accounts: [0],
+            };
+
+            let donate_ix = Instruction {
+                program_id: program_id(),
+                accounts: vec![
+                    AccountMeta::new(contributor.pubkey(), true),
+                    AccountMeta::new(*mint, false),
+                    AccountMeta::new(fundraiser.0, false),
+                    AccountMeta::new(contributor_pda.0, false),
+                    AccountMeta::new(contributor_ata, false),
+                    AccountMeta::new(*vault, false),
+                    AccountMeta::new(*system_program, false),
+                    AccountMeta::new(*token_program, false),
+                    AccountMeta::new(*ata_program, false),
+                    AccountMeta::new(rent::ID, false),
+                    AccountMeta::new_readonly(*config, false),
+                    AccountMeta::new_readonly(*denylist, false),
+                ],
+                data: [
+                    (FundraiserInstruction::Deposit as u8)
+                        .to_le_bytes()
+                        .to_vec(),
+                    contribute_data.to_bytes().to_vec(),
+                ]
+                .concat(),
+            };
+
+            let message = Message::new(&[donate_ix], Some(&contributor.pubkey()));
+            let transaction = Transaction::new(&[contributor], message, svm.latest_blockhash());
+
+            svm.send_transaction(transaction).map(|_| contributor_pda.0)
+        };
+
+        assert!(deposit(svm, sanctioned).is_err());
+
+        let contributor_pda = deposit(svm, &contributor).unwrap();
+
+        println!("\nDonate with denylist transaction sucessfull");
+
+        let contributor_account = svm.get_account(&contributor_pda).unwrap();
+        let contributor_state = bytemuck::from_bytes::<Contributor>(&contributor_account.data);
+        assert_eq!(u64::from_le_bytes(contributor_state.amount), 100_000_000);
+
+        Ok(())
+    }
+
+    // the contributor is denylisted after depositing - the early withdrawal can't pay it back
+    pub fn withdraw_to_denylisted_function(
+        svm: &mut LiteSVM,
+        state: &ReusableState,
+        contributor: &Keypair,
+    ) -> Result<(), Error> {
+        let ReusableState {
+            maker,
+            fundraiser,
+            mint,
+            vault,
+            token_program,
+            config,
+            ..
+        } = state;
+
+        let (denylist, _) = init_denylist_function(svm, state)?;
+
+        let update_denylist_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new_readonly(maker.pubkey(), true),
+                AccountMeta::new_readonly(*config, false),
+                AccountMeta::new(denylist, false),
+            ],
+            data: [
+                vec![FundraiserInstruction::UpdateDenylist as u8, DENYLIST_ADD],
+                contributor.pubkey().to_bytes().to_vec(),
+            ]
+            .concat(),
+        };
+
+        let message = Message::new(&[update_denylist_ix], Some(&maker.pubkey()));
+        let transaction = Transaction::new(&[&maker], message, svm.latest_blockhash());
+        svm.send_transaction(transaction).unwrap();
+
+        let contributor_pda = Pubkey::find_program_address(
+            &[
+                b"contributor".as_ref(),
+                fundraiser.0.as_ref(),
+                contributor.pubkey().as_ref(),
+            ],
+            &program_id(),
+        );
+        let contributor_ata =
+            spl_associated_token_account::get_associated_token_address(&contributor.pubkey(), mint);
+
+        let withdraw_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(contributor.pubkey(), true),
+                AccountMeta::new(fundraiser.0, false),
+                AccountMeta::new(contributor_pda.0, false),
+                AccountMeta::new_readonly(*token_program, false),
+                AccountMeta::new_readonly(*config, false),
+                AccountMeta::new_readonly(denylist, false),
+                AccountMeta::new(*vault, false),
+                AccountMeta::new(contributor_ata, false),
+            ],
+            data: vec![FundraiserInstruction::WithdrawContribution as u8],
+        };
+
+        let message = Message::new(&[withdraw_ix], Some(&contributor.pubkey()));
+        let transaction = Transaction::new(&[contributor], message, svm.latest_blockhash());
+        assert!(svm.send_transaction(transaction).is_err());
+
+        let contributor_account = svm.get_account(&contributor_pda.0).unwrap();
+        let contributor_state = bytemuck::from_bytes::<Contributor>(&contributor_account.data);
+        assert_eq!(u64::from_le_bytes(contributor_state.amount), 100_000_000);
+
+        Ok(())
+    }
+}
@@ -2,8 +2,7 @@
 pub mod donate {
     use core::fmt::Error;
 
-    use crate::instructions::{FundraiserInstruction, InitData};
-    use crate::state::Contributor;
+    use crate::instructions::{ContributeData, FundraiserInstruction};
     use crate::tests::tests::{program_id, ReusableState};
     use litesvm::LiteSVM;
     use litesvm_token::{CreateAssociatedTokenAccount, MintTo};
@@ -15,7 +14,8 @@ pub mod donate {
     use solana_signer::Signer;
     use solana_transaction::Transaction;
 
-    pub fn donate_function(svm: &mut LiteSVM, state: &ReusableState) -> Result<(), Error> {
+    // returns the contributor so later steps can act on their behalf
+    pub fn donate_function(svm: &mut LiteSVM, state: &ReusableState) -> Result<Keypair, Error> {
         let ReusableState {
             maker,
             fundraiser,
@@ -24,6 +24,7 @@ pub mod donate {
             system_program,
             token_program,
             ata_program,
+            config,
             ..
         } = state;
 
@@ -49,12 +50,19 @@ pub mod donate {
             .unwrap();
 
         let contributor_pda = solana_pubkey::Pubkey::find_program_address(
-            &[b"contributor".as_ref(), contributor.pubkey().as_ref()],
+            &[
+                b"contributor".as_ref(),
+                fundraiser.0.as_ref(),
+                contributor.pubkey().as_ref(),
+            ],
             &program_id(),
         );
 
-        let initialize_data = Contributor {
+        let initialize_data = ContributeData {
             amount: amount_to_donate.to_le_bytes(),
+            min_out: 0u64.to_le_bytes(),
+            swap: [0],
+            swap_accounts: [0],
         };
 
         let donate_ix = Instruction {
@@ -70,6 +78,7 @@ pub mod donate {
                 AccountMeta::new(*token_program, false),
                 AccountMeta::new(*ata_program, false),
                 AccountMeta::new(rent_sysvar, false),
+                AccountMeta::new_readonly(*config, false),
             ],
             data: [
                 (FundraiserInstruction::Deposit as u8)
@@ -92,6 +101,6 @@ pub mod donate {
         // Log transaction details
         println!("\nDonate transaction sucessfull");
         println!("CUs Consumed: {}", tx.compute_units_consumed);
-        Ok(())
+        Ok(contributor)
     }
 }
@@ -0,0 +1,112 @@
+#[cfg(test)]
+pub mod donate_for {
+    use core::fmt::Error;
+
+    use crate::instructions::{ContributeData, FundraiserInstruction};
+    use crate::state::Contributor;
+    use crate::tests::tests::{program_id, ReusableState};
+    use litesvm::LiteSVM;
+    use litesvm_token::{CreateAssociatedTokenAccount, MintTo};
+    use solana_instruction::{AccountMeta, Instruction};
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_native_token::LAMPORTS_PER_SOL;
+    use solana_pubkey::Pubkey;
+    use solana_sdk_ids::sysvar::rent;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    // a payer gifts 100 usdc to a wallet that never signs
+    pub fn donate_for_function(svm: &mut LiteSVM, state: &ReusableState) -> Result<(), Error> {
+        let ReusableState {
+            maker,
+            fundraiser,
+            mint,
+            vault,
+            system_program,
+            token_program,
+            ata_program,
+            config,
+            ..
+        } = state;
+
+        let payer = Keypair::new();
+        let beneficiary = Keypair::new();
+
+        svm.airdrop(&payer.pubkey(), 10 * LAMPORTS_PER_SOL)
+            .expect("Airdrop failed");
+
+        let payer_ata = CreateAssociatedTokenAccount::new(svm, &payer, mint)
+            .owner(&payer.pubkey())
+            .send()
+            .unwrap();
+
+        MintTo::new(svm, maker, mint, &payer_ata, 1_000_000_000)
+            .send()
+            .unwrap();
+
+        let contributor_pda = |wallet: &Pubkey| {
+            Pubkey::find_program_address(
+                &[
+                    b"contributor".as_ref(),
+                    fundraiser.0.as_ref(),
+                    wallet.as_ref(),
+                ],
+                &program_id(),
+            )
+            .0
+        };
+        let beneficiary_pda = contributor_pda(&beneficiary.pubkey());
+
+        let contribute_data = ContributeData {
+            amount: 100_000_000u64.to_le_bytes(),
+            min_out: 0u64.to_le_bytes(),
+            swap: [0],
+            swap_accounts: [0],
+        };
+
+        let donate_for_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new_readonly(beneficiary.pubkey(), false),
+                AccountMeta::new(*mint, false),
+                AccountMeta::new(fundraiser.0, false),
+                AccountMeta::new(beneficiary_pda, false),
+                AccountMeta::new(payer_ata, false),
+                AccountMeta::new(*vault, false),
+                AccountMeta::new(*system_program, false),
+                AccountMeta::new(*token_program, false),
+                AccountMeta::new(*ata_program, false),
+                AccountMeta::new(rent::ID, false),
+                AccountMeta::new_readonly(*config, false),
+            ],
+            data: [
+                (FundraiserInstruction::DepositFor as u8)
+                    .to_le_bytes()
+                    .to_vec(),
+                contribute_data.to_bytes().to_vec(),
+            ]
+            .concat(),
+        };
+
+        let message = Message::new(&[donate_for_ix], Some(&payer.pubkey()));
+        let recent_blockhash = svm.latest_blockhash();
+
+        let transaction = Transaction::new(&[&payer], message, recent_blockhash);
+
+        let tx = svm.send_transaction(transaction).unwrap();
+
+        println!("\nDonate for transaction sucessfull");
+        println!("CUs Consumed: {}", tx.compute_units_consumed);
+
+        let beneficiary_account = svm.get_account(&beneficiary_pda).unwrap();
+        let beneficiary_state = bytemuck::from_bytes::<Contributor>(&beneficiary_account.data);
+        assert_eq!(u64::from_le_bytes(beneficiary_state.amount), 100_000_000);
+
+        // the payer gets no record of its own
+        assert!(svm.get_account(&contributor_pda(&payer.pubkey())).is_none());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,152 @@
+#[cfg(test)]
+pub mod early_withdraw {
+    use core::fmt::Error;
+
+    use bytemuck::Zeroable;
+
+    use crate::instructions::{FundraiserInstruction, InitData};
+    use crate::state::{Contributor, Fundraiser, GOAL_MODE_TOKEN};
+    use crate::tests::tests::{program_id, token_balance, ReusableState};
+    use litesvm::LiteSVM;
+    use solana_instruction::{AccountMeta, Instruction};
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_pubkey::Pubkey;
+    use solana_sdk_ids::sysvar::rent;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    // pledge drive letting contributors back out for a 10% penalty
+    pub fn create_early_withdraw_fundraiser_function(
+        svm: &mut LiteSVM,
+        state: &ReusableState,
+    ) -> Result<(), Error> {
+        let ReusableState {
+            maker,
+            fundraiser,
+            mint,
+            vault,
+            system_program,
+            token_program,
+            ata_program,
+            config,
+            ..
+        } = state;
+
+        let initialize_data = InitData {
+            amount_to_raise: 10_000_000_000u64.to_le_bytes(),
+            duration: 3u8.to_le_bytes(),
+            goal_mode: [GOAL_MODE_TOKEN],
+            early_withdraw: [1],
+            early_withdraw_penalty_bps: 1_000u16.to_le_bytes(),
+            ..Zeroable::zeroed()
+        };
+
+        let make_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new(*mint, false),
+                AccountMeta::new(fundraiser.0, false),
+                AccountMeta::new(*vault, false),
+                AccountMeta::new(*system_program, false),
+                AccountMeta::new(*token_program, false),
+                AccountMeta::new(*ata_program, false),
+                AccountMeta::new(rent::ID, false),
+                AccountMeta::new_readonly(*config, false),
+            ],
+            data: [
+                (FundraiserInstruction::Initialize as u8)
+                    .to_le_bytes()
+                    .to_vec(),
+                initialize_data.to_bytes().to_vec(),
+            ]
+            .concat(),
+        };
+
+        let message = Message::new(&[make_ix], Some(&maker.pubkey()));
+        let recent_blockhash = svm.latest_blockhash();
+
+        let transaction = Transaction::new(&[&maker], message, recent_blockhash);
+
+        let tx = svm.send_transaction(transaction).unwrap();
+
+        println!("\nEarly withdraw initialize transaction sucessfull");
+        println!("CUs Consumed: {}", tx.compute_units_consumed);
+
+        let fundraiser_account = svm.get_account(&fundraiser.0).unwrap();
+        let fundraiser_state =
+            bytemuck::from_bytes::<Fundraiser>(&fundraiser_account.data[..Fundraiser::LEN]);
+        assert!(fundraiser_state.allows_early_withdraw());
+
+        Ok(())
+    }
+
+    // the contributor gets 90 of their 100 usdc back before the deadline
+    pub fn withdraw_contribution_function(
+        svm: &mut LiteSVM,
+        state: &ReusableState,
+        contributor: &Keypair,
+    ) -> Result<(), Error> {
+        let ReusableState {
+            fundraiser,
+            mint,
+            vault,
+            token_program,
+            config,
+            ..
+        } = state;
+
+        let contributor_pda = Pubkey::find_program_address(
+            &[
+                b"contributor".as_ref(),
+                fundraiser.0.as_ref(),
+                contributor.pubkey().as_ref(),
+            ],
+            &program_id(),
+        );
+        let contributor_ata =
+            spl_associated_token_account::get_associated_token_address(&contributor.pubkey(), mint);
+
+        let withdraw_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(contributor.pubkey(), true),
+                AccountMeta::new(fundraiser.0, false),
+                AccountMeta::new(contributor_pda.0, false),
+                AccountMeta::new_readonly(*token_program, false),
+                AccountMeta::new_readonly(*config, false),
+                AccountMeta::new(*vault, false),
+                AccountMeta::new(contributor_ata, false),
+            ],
+            data: vec![FundraiserInstruction::WithdrawContribution as u8],
+        };
+
+        let message = Message::new(&[withdraw_ix], Some(&contributor.pubkey()));
+        let recent_blockhash = svm.latest_blockhash();
+
+        let transaction = Transaction::new(&[contributor], message, recent_blockhash);
+
+        let tx = svm.send_transaction(transaction).unwrap();
+
+        println!("\nWithdraw contribution transaction sucessfull");
+        println!("CUs Consumed: {}", tx.compute_units_consumed);
+
+        assert_eq!(token_balance(svm, &contributor_ata), 990_000_000);
+        assert_eq!(token_balance(svm, vault), 10_000_000);
+
+        let contributor_account = svm.get_account(&contributor_pda.0).unwrap();
+        let contributor_state = bytemuck::from_bytes::<Contributor>(&contributor_account.data);
+        assert_eq!(u64::from_le_bytes(contributor_state.amount), 0);
+
+        let fundraiser_account = svm.get_account(&fundraiser.0).unwrap();
+        let fundraiser_state =
+            bytemuck::from_bytes::<Fundraiser>(&fundraiser_account.data[..Fundraiser::LEN]);
+        assert_eq!(
+            u64::from_le_bytes(fundraiser_state.current_amount),
+            10_000_000
+        );
+
+        Ok(())
+    }
+}
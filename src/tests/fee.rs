@@ -0,0 +1,112 @@
+#[cfg(test)]
+pub mod fee {
+    use core::fmt::Error;
+
+    use crate::instructions::{ConfigData, FundraiserInstruction};
+    use crate::tests::oracle::oracle::ORACLE_PROGRAM;
+    use crate::tests::tests::{program_id, token_balance, ReusableState};
+    use litesvm::LiteSVM;
+    use litesvm_token::CreateAssociatedTokenAccount;
+    use solana_instruction::{AccountMeta, Instruction};
+    use solana_message::Message;
+    use solana_pubkey::Pubkey;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    // admin sets the platform fee paid to `fee_recipient`
+    pub fn update_config_function(
+        svm: &mut LiteSVM,
+        state: &ReusableState,
+        fee_bps: u16,
+        fee_recipient: &Pubkey,
+    ) -> Result<(), Error> {
+        let ReusableState { maker, config, .. } = state;
+
+        let config_data = ConfigData {
+            fee_bps: fee_bps.to_le_bytes(),
+            fee_recipient: fee_recipient.to_bytes(),
+            oracle_program: ORACLE_PROGRAM.to_bytes(),
+        };
+
+        let update_config_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new_readonly(maker.pubkey(), true),
+                AccountMeta::new(*config, false),
+            ],
+            data: [
+                (FundraiserInstruction::UpdateConfig as u8)
+                    .to_le_bytes()
+                    .to_vec(),
+                config_data.to_bytes().to_vec(),
+            ]
+            .concat(),
+        };
+
+        let message = Message::new(&[update_config_ix], Some(&maker.pubkey()));
+        let recent_blockhash = svm.latest_blockhash();
+
+        let transaction = Transaction::new(&[&maker], message, recent_blockhash);
+
+        let tx = svm.send_transaction(transaction).unwrap();
+
+        println!("\nUpdate config transaction sucessfull");
+        println!("CUs Consumed: {}", tx.compute_units_consumed);
+
+        Ok(())
+    }
+
+    // claims a 100 usdc raise split 70 / 30, created while the fee was 5%
+    pub fn claim_with_fee_function(
+        svm: &mut LiteSVM,
+        state: &ReusableState,
+        fee_recipient: &Pubkey,
+        sponsor_ata: &Pubkey,
+    ) -> Result<(), Error> {
+        let ReusableState {
+            maker,
+            maker_ata,
+            fundraiser,
+            mint,
+            vault,
+            token_program,
+            ..
+        } = state;
+
+        let fee_ata = CreateAssociatedTokenAccount::new(svm, maker, mint)
+            .owner(fee_recipient)
+            .send()
+            .unwrap();
+
+        let claim_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new(fundraiser.0, false),
+                AccountMeta::new_readonly(*token_program, false),
+                AccountMeta::new(*vault, false),
+                AccountMeta::new(fee_ata, false),
+                AccountMeta::new(*maker_ata, false),
+                AccountMeta::new(*sponsor_ata, false),
+            ],
+            data: vec![FundraiserInstruction::Claim as u8],
+        };
+
+        let message = Message::new(&[claim_ix], Some(&maker.pubkey()));
+        let recent_blockhash = svm.latest_blockhash();
+
+        let transaction = Transaction::new(&[&maker], message, recent_blockhash);
+
+        let tx = svm.send_transaction(transaction).unwrap();
+
+        println!("\nClaim with fee transaction sucessfull");
+        println!("CUs Consumed: {}", tx.compute_units_consumed);
+
+        // 5% fee, the 95 left split 70 / 30
+        assert_eq!(token_balance(svm, &fee_ata), 5_000_000);
+        assert_eq!(token_balance(svm, maker_ata), 66_500_000);
+        assert_eq!(token_balance(svm, sponsor_ata), 28_500_000);
+
+        Ok(())
+    }
+}
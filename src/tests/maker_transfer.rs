@@ -0,0 +1,85 @@
+#[cfg(test)]
+pub mod maker_transfer {
+    use core::fmt::Error;
+
+    use crate::instructions::FundraiserInstruction;
+    use crate::state::Fundraiser;
+    use crate::tests::tests::{program_id, ReusableState};
+    use litesvm::LiteSVM;
+    use solana_instruction::{AccountMeta, Instruction};
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_native_token::LAMPORTS_PER_SOL;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    // maker hands the fundraiser to a new treasury key, which accepts and pauses it
+    pub fn transfer_maker_function(svm: &mut LiteSVM, state: &ReusableState) -> Result<(), Error> {
+        let ReusableState {
+            maker, fundraiser, ..
+        } = state;
+
+        let new_maker = Keypair::new();
+
+        svm.airdrop(&new_maker.pubkey(), LAMPORTS_PER_SOL)
+            .expect("Airdrop failed");
+
+        let propose_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new_readonly(maker.pubkey(), true),
+                AccountMeta::new(fundraiser.0, false),
+                AccountMeta::new_readonly(new_maker.pubkey(), false),
+            ],
+            data: vec![FundraiserInstruction::ProposeMakerTransfer as u8],
+        };
+
+        let accept_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new_readonly(new_maker.pubkey(), true),
+                AccountMeta::new(fundraiser.0, false),
+            ],
+            data: vec![FundraiserInstruction::AcceptMakerTransfer as u8],
+        };
+
+        let message = Message::new(&[propose_ix, accept_ix], Some(&maker.pubkey()));
+        let recent_blockhash = svm.latest_blockhash();
+
+        let transaction = Transaction::new(&[maker, &new_maker], message, recent_blockhash);
+
+        let tx = svm.send_transaction(transaction).unwrap();
+
+        println!("\nMaker transfer transaction sucessfull");
+        println!("CUs Consumed: {}", tx.compute_units_consumed);
+
+        let fundraiser_account = svm.get_account(&fundraiser.0).unwrap();
+        let fundraiser_state =
+            bytemuck::from_bytes::<Fundraiser>(&fundraiser_account.data[..Fundraiser::LEN]);
+        assert_eq!(fundraiser_state.maker, new_maker.pubkey().to_bytes());
+        assert_eq!(fundraiser_state.creator, maker.pubkey().to_bytes());
+        assert_eq!(fundraiser_state.pending_maker, [0u8; 32]);
+        // the maker was the beneficiary, payouts now go to the new one
+        assert_eq!(fundraiser_state.beneficiary, new_maker.pubkey().to_bytes());
+
+        // only the new maker controls the fundraiser now
+        let pause_ix = |signer: &Keypair| Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new_readonly(signer.pubkey(), true),
+                AccountMeta::new(fundraiser.0, false),
+            ],
+            data: vec![FundraiserInstruction::Pause as u8],
+        };
+
+        let message = Message::new(&[pause_ix(maker)], Some(&maker.pubkey()));
+        let transaction = Transaction::new(&[maker], message, svm.latest_blockhash());
+        assert!(svm.send_transaction(transaction).is_err());
+
+        let message = Message::new(&[pause_ix(&new_maker)], Some(&new_maker.pubkey()));
+        let transaction = Transaction::new(&[&new_maker], message, svm.latest_blockhash());
+        svm.send_transaction(transaction).unwrap();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,270 @@
+#[cfg(test)]
+pub mod matching {
+    use core::fmt::Error;
+
+    use crate::instructions::{ContributeData, FundraiserInstruction, MatchingData};
+    use crate::state::{Fundraiser, Matching};
+    use crate::tests::tests::{program_id, token_balance, warp_forward, ReusableState};
+    use litesvm::LiteSVM;
+    use litesvm_token::{CreateAssociatedTokenAccount, MintTo};
+    use solana_instruction::{AccountMeta, Instruction};
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_native_token::LAMPORTS_PER_SOL;
+    use solana_pubkey::Pubkey;
+    use solana_sdk_ids::sysvar::rent;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    // maker sponsors a 1:1 match capped at 150 usdc, returns (matching, matching vault)
+    pub fn fund_matching_function(
+        svm: &mut LiteSVM,
+        state: &ReusableState,
+    ) -> Result<(Pubkey, Pubkey), Error> {
+        let ReusableState {
+            maker,
+            maker_ata,
+            fundraiser,
+            mint,
+            system_program,
+            token_program,
+            ata_program,
+            ..
+        } = state;
+
+        MintTo::new(svm, maker, mint, maker_ata, 1_000_000_000)
+            .send()
+            .unwrap();
+
+        let matching =
+            Pubkey::find_program_address(&[b"matching", fundraiser.0.as_ref()], &program_id()).0;
+        let matching_vault =
+            spl_associated_token_account::get_associated_token_address(&matching, mint);
+
+        let matching_data = MatchingData {
+            ratio_bps: 10_000u16.to_le_bytes(),
+            cap: 150_000_000u64.to_le_bytes(),
+        };
+
+        let fund_matching_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new(*mint, false),
+                AccountMeta::new(fundraiser.0, false),
+                AccountMeta::new(matching, false),
+                AccountMeta::new(*maker_ata, false),
+                AccountMeta::new(matching_vault, false),
+                AccountMeta::new(*system_program, false),
+                AccountMeta::new(*token_program, false),
+                AccountMeta::new(*ata_program, false),
+                AccountMeta::new_readonly(maker.pubkey(), true),
+            ],
+            data: [
+                (FundraiserInstruction::FundMatching as u8)
+                    .to_le_bytes()
+                    .to_vec(),
+                matching_data.to_bytes().to_vec(),
+            ]
+            .concat(),
+        };
+
+        let message = Message::new(&[fund_matching_ix], Some(&maker.pubkey()));
+        let recent_blockhash = svm.latest_blockhash();
+
+        let transaction = Transaction::new(&[&maker], message, recent_blockhash);
+
+        let tx = svm.send_transaction(transaction).unwrap();
+
+        println!("\nFund matching transaction sucessfull");
+        println!("CUs Consumed: {}", tx.compute_units_consumed);
+
+        assert_eq!(token_balance(svm, &matching_vault), 150_000_000);
+
+        Ok((matching, matching_vault))
+    }
+
+    pub fn donate_with_matching_function(
+        svm: &mut LiteSVM,
+        state: &ReusableState,
+        matching: &Pubkey,
+        matching_vault: &Pubkey,
+    ) -> Result<(), Error> {
+        let ReusableState {
+            maker,
+            fundraiser,
+            mint,
+            vault,
+            system_program,
+            token_program,
+            ata_program,
+            config,
+            ..
+        } = state;
+
+        let amount_to_donate: u64 = 100_000_000; // 100 usdc - 6 decimals
+
+        let contributor = Keypair::new();
+
+        svm.airdrop(&contributor.pubkey(), 10 * LAMPORTS_PER_SOL)
+            .expect("Airdrop failed");
+
+        let contributor_ata = CreateAssociatedTokenAccount::new(svm, &contributor, mint)
+            .owner(&contributor.pubkey())
+            .send()
+            .unwrap();
+
+        MintTo::new(svm, maker, mint, &contributor_ata, 1_000_000_000)
+            .send()
+            .unwrap();
+
+        let contributor_pda = Pubkey::find_program_address(
+            &[
+                b"contributor".as_ref(),
+                fundraiser.0.as_ref(),
+                contributor.pubkey().as_ref(),
+            ],
+            &program_id(),
+        );
+
+        let contribute_data = ContributeData {
+            amount: amount_to_donate.to_le_bytes(),
+            min_out: 0u64.to_le_bytes(),
+            swap: [0],
+            swap_accounts: [0],
+        };
+
+        let donate_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(contributor.pubkey(), true),
+                AccountMeta::new(*mint, false),
+                AccountMeta::new(fundraiser.0, false),
+                AccountMeta::new(contributor_pda.0, false),
+                AccountMeta::new(contributor_ata, false),
+                AccountMeta::new(*vault, false),
+                AccountMeta::new(*system_program, false),
+                AccountMeta::new(*token_program, false),
+                AccountMeta::new(*ata_program, false),
+                AccountMeta::new(rent::ID, false),
+                AccountMeta::new_readonly(*config, false),
+                AccountMeta::new(*matching, false),
+                AccountMeta::new(*matching_vault, false),
+            ],
+            data: [
+                (FundraiserInstruction::Deposit as u8)
+                    .to_le_bytes()
+                    .to_vec(),
+                contribute_data.to_bytes().to_vec(),
+            ]
+            .concat(),
+        };
+
+        let message = Message::new(&[donate_ix], Some(&contributor.pubkey()));
+        let recent_blockhash = svm.latest_blockhash();
+
+        let transaction = Transaction::new(&[&contributor], message, recent_blockhash);
+
+        let tx = svm.send_transaction(transaction).unwrap();
+
+        println!("\nDonate with matching transaction sucessfull");
+        println!("CUs Consumed: {}", tx.compute_units_consumed);
+
+        // 100 donated + 100 matched
+        assert_eq!(token_balance(svm, vault), 200_000_000);
+        assert_eq!(token_balance(svm, matching_vault), 50_000_000);
+
+        let fundraiser_account = svm.get_account(&fundraiser.0).unwrap();
+        let fundraiser_state =
+            bytemuck::from_bytes::<Fundraiser>(&fundraiser_account.data[..Fundraiser::LEN]);
+        assert_eq!(
+            u64::from_le_bytes(fundraiser_state.matched_amount),
+            100_000_000
+        );
+
+        let matching_account = svm.get_account(matching).unwrap();
+        let matching_state = bytemuck::from_bytes::<Matching>(&matching_account.data);
+        assert_eq!(
+            u64::from_le_bytes(matching_state.matched_amount),
+            100_000_000
+        );
+
+        Ok(())
+    }
+
+    // the raise failed, so the 50 usdc left of the cap and the 100 matched go back to the sponsor
+    pub fn finalize_matching_function(
+        svm: &mut LiteSVM,
+        state: &ReusableState,
+        matching: &Pubkey,
+        matching_vault: &Pubkey,
+    ) -> Result<(), Error> {
+        let ReusableState {
+            maker_ata,
+            fundraiser,
+            vault,
+            token_program,
+            ..
+        } = state;
+
+        warp_forward(svm, 4 * 24 * 60 * 60);
+
+        let sponsor_balance = token_balance(svm, maker_ata);
+
+        // permissionless - anyone can pay for the finalize
+        let finalizer = Keypair::new();
+        svm.airdrop(&finalizer.pubkey(), LAMPORTS_PER_SOL)
+            .expect("Airdrop failed");
+
+        let finalize_matching_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(fundraiser.0, false),
+                AccountMeta::new(*matching, false),
+                AccountMeta::new(*matching_vault, false),
+                AccountMeta::new(*maker_ata, false),
+                AccountMeta::new_readonly(*token_program, false),
+                AccountMeta::new(*vault, false),
+            ],
+            data: (FundraiserInstruction::FinalizeMatching as u8)
+                .to_le_bytes()
+                .to_vec(),
+        };
+
+        let message = Message::new(&[finalize_matching_ix.clone()], Some(&finalizer.pubkey()));
+        let recent_blockhash = svm.latest_blockhash();
+
+        let transaction = Transaction::new(&[&finalizer], message, recent_blockhash);
+
+        let tx = svm.send_transaction(transaction).unwrap();
+
+        println!("\nFinalize matching transaction sucessfull");
+        println!("CUs Consumed: {}", tx.compute_units_consumed);
+
+        assert_eq!(token_balance(svm, matching_vault), 0);
+        assert_eq!(token_balance(svm, vault), 100_000_000);
+        assert_eq!(token_balance(svm, maker_ata), sponsor_balance + 150_000_000);
+
+        let fundraiser_account = svm.get_account(&fundraiser.0).unwrap();
+        let fundraiser_state =
+            bytemuck::from_bytes::<Fundraiser>(&fundraiser_account.data[..Fundraiser::LEN]);
+        assert_eq!(u64::from_le_bytes(fundraiser_state.matched_amount), 0);
+        assert_eq!(
+            u64::from_le_bytes(
+                Fundraiser::accepted_mints(&fundraiser_account.data)[0].current_amount
+            ),
+            100_000_000
+        );
+
+        // the matched funds only go back once
+        svm.expire_blockhash();
+        let message = Message::new(&[finalize_matching_ix], Some(&finalizer.pubkey()));
+        let transaction = Transaction::new(&[&finalizer], message, svm.latest_blockhash());
+        svm.send_transaction(transaction).unwrap();
+
+        assert_eq!(token_balance(svm, vault), 100_000_000);
+        assert_eq!(token_balance(svm, maker_ata), sponsor_balance + 150_000_000);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,158 @@
+#[cfg(test)]
+pub mod milestones {
+    use core::fmt::Error;
+
+    use bytemuck::Zeroable;
+
+    use crate::instructions::{FundraiserInstruction, InitData};
+    use crate::state::{Fundraiser, GOAL_MODE_TOKEN, MILESTONE_PENDING, MILESTONE_RELEASED};
+    use crate::tests::tests::{program_id, token_balance, ReusableState};
+    use litesvm::LiteSVM;
+    use solana_instruction::{AccountMeta, Instruction};
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_sdk_ids::sysvar::rent;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    // 100 usdc goal released in two 50% milestones, returns the approver
+    pub fn create_milestone_fundraiser_function(
+        svm: &mut LiteSVM,
+        state: &ReusableState,
+    ) -> Result<Keypair, Error> {
+        let ReusableState {
+            maker,
+            fundraiser,
+            mint,
+            vault,
+            system_program,
+            token_program,
+            ata_program,
+            config,
+            ..
+        } = state;
+
+        let approver = Keypair::new();
+
+        let mut milestones_bps = [[0u8; 2]; 8];
+        milestones_bps[0] = 5_000u16.to_le_bytes();
+        milestones_bps[1] = 5_000u16.to_le_bytes();
+
+        let initialize_data = InitData {
+            amount_to_raise: 100_000_000u64.to_le_bytes(),
+            duration: 3u8.to_le_bytes(),
+            goal_mode: [GOAL_MODE_TOKEN],
+            approver: approver.pubkey().to_bytes(),
+            milestones_count: [2],
+            milestones_bps,
+            ..Zeroable::zeroed()
+        };
+
+        let make_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new(*mint, false),
+                AccountMeta::new(fundraiser.0, false),
+                AccountMeta::new(*vault, false),
+                AccountMeta::new(*system_program, false),
+                AccountMeta::new(*token_program, false),
+                AccountMeta::new(*ata_program, false),
+                AccountMeta::new(rent::ID, false),
+                AccountMeta::new_readonly(*config, false),
+            ],
+            data: [
+                (FundraiserInstruction::Initialize as u8)
+                    .to_le_bytes()
+                    .to_vec(),
+                initialize_data.to_bytes().to_vec(),
+            ]
+            .concat(),
+        };
+
+        let message = Message::new(&[make_ix], Some(&maker.pubkey()));
+        let recent_blockhash = svm.latest_blockhash();
+
+        let transaction = Transaction::new(&[&maker], message, recent_blockhash);
+
+        let tx = svm.send_transaction(transaction).unwrap();
+
+        println!("\nMilestone initialize transaction sucessfull");
+        println!("CUs Consumed: {}", tx.compute_units_consumed);
+
+        let fundraiser_account = svm.get_account(&fundraiser.0).unwrap();
+        let fundraiser_state =
+            bytemuck::from_bytes::<Fundraiser>(&fundraiser_account.data[..Fundraiser::LEN]);
+        assert_eq!(fundraiser_state.milestones_count, [2]);
+        assert_eq!(fundraiser_state.milestones[1].status, [MILESTONE_PENDING]);
+
+        Ok(approver)
+    }
+
+    // submit, approve and release the first milestone once the raise succeeded
+    pub fn release_first_milestone_function(
+        svm: &mut LiteSVM,
+        state: &ReusableState,
+        approver: &Keypair,
+    ) -> Result<(), Error> {
+        let ReusableState {
+            maker,
+            maker_ata,
+            fundraiser,
+            vault,
+            token_program,
+            ..
+        } = state;
+
+        let submit_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new(fundraiser.0, false),
+            ],
+            data: vec![FundraiserInstruction::SubmitMilestone as u8, 0],
+        };
+
+        let approve_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(approver.pubkey(), true),
+                AccountMeta::new(fundraiser.0, false),
+            ],
+            data: vec![FundraiserInstruction::ApproveMilestone as u8, 0],
+        };
+
+        let release_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new(fundraiser.0, false),
+                AccountMeta::new_readonly(*token_program, false),
+                AccountMeta::new(*vault, false),
+                AccountMeta::new(*maker_ata, false),
+            ],
+            data: vec![FundraiserInstruction::ReleaseTranche as u8, 0],
+        };
+
+        let message = Message::new(&[submit_ix, approve_ix, release_ix], Some(&maker.pubkey()));
+        let recent_blockhash = svm.latest_blockhash();
+
+        let transaction = Transaction::new(&[&maker, approver], message, recent_blockhash);
+
+        let tx = svm.send_transaction(transaction).unwrap();
+
+        println!("\nRelease tranche transaction sucessfull");
+        println!("CUs Consumed: {}", tx.compute_units_consumed);
+
+        // half of the 100 usdc raised
+        assert_eq!(token_balance(svm, maker_ata), 50_000_000);
+        assert_eq!(token_balance(svm, vault), 50_000_000);
+
+        let fundraiser_account = svm.get_account(&fundraiser.0).unwrap();
+        let fundraiser_state =
+            bytemuck::from_bytes::<Fundraiser>(&fundraiser_account.data[..Fundraiser::LEN]);
+        assert_eq!(fundraiser_state.milestones[0].status, [MILESTONE_RELEASED]);
+
+        Ok(())
+    }
+}
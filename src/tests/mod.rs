@@ -1,5 +1,29 @@
+pub mod abort;
+pub mod allowlist;
+pub mod attestation;
+pub mod beneficiary;
 pub mod create_fundraiser;
+pub mod denylist;
 pub mod donate;
+pub mod donate_for;
+pub mod early_withdraw;
+pub mod fee;
+pub mod maker_transfer;
+pub mod matching;
+pub mod milestones;
+pub mod multi_mint;
+pub mod multisig;
+pub mod oracle;
+pub mod pause;
+pub mod pledge;
+pub mod receipt;
+pub mod rewards;
+pub mod round;
+pub mod splits;
+pub mod swap;
+pub mod transfer_contribution;
+pub mod vesting;
+pub mod voting;
 
 #[cfg(test)]
 mod tests {
@@ -10,16 +34,78 @@ mod tests {
         CreateAssociatedTokenAccount, CreateMint,
     };
 
+    use solana_instruction::{AccountMeta, Instruction};
     use solana_keypair::Keypair;
+    use solana_message::Message;
 
+    use solana_account::Account;
     use solana_native_token::LAMPORTS_PER_SOL;
     use solana_pubkey::Pubkey;
-    use solana_sdk_ids::system_program;
+    use solana_sdk_ids::{bpf_loader_upgradeable, system_program, sysvar::clock};
     use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::instructions::{ConfigData, FundraiserInstruction};
 
     use crate::tests::{
+        abort::abort::{
+            abort_fundraiser_function, claim_remainder_function, repropose_abort_function,
+        },
+        allowlist::allowlist::{create_allowlist_fundraiser_function, donate_allowlisted_function},
+        attestation::attestation::{create_attested_fundraiser_function, donate_attested_function},
+        beneficiary::beneficiary::set_beneficiary_function,
         create_fundraiser::create_fundraiser::create_fundraiser_function,
+        denylist::denylist::{
+            donate_with_denylist_function, init_denylist_function, withdraw_to_denylisted_function,
+        },
         donate::donate::donate_function,
+        donate_for::donate_for::donate_for_function,
+        early_withdraw::early_withdraw::{
+            create_early_withdraw_fundraiser_function, withdraw_contribution_function,
+        },
+        fee::fee::{claim_with_fee_function, update_config_function},
+        maker_transfer::maker_transfer::transfer_maker_function,
+        matching::matching::{
+            donate_with_matching_function, finalize_matching_function, fund_matching_function,
+        },
+        milestones::milestones::{
+            create_milestone_fundraiser_function, release_first_milestone_function,
+        },
+        multi_mint::multi_mint::{
+            create_multi_mint_fundraiser_function, donate_second_mint_function,
+        },
+        multisig::multisig::{init_multisig_function, multisig_pause_function},
+        oracle::oracle::{
+            create_usd_fundraiser_function, donate_usd_function, donate_usd_rejected_function,
+            ORACLE_PROGRAM,
+        },
+        pause::pause::{
+            donate_while_paused_function, pause_and_resume_function, set_paused_function,
+        },
+        pledge::pledge::{
+            create_pledge_fundraiser_function, pledge_and_settle_function, pledge_default_function,
+            pledge_fundraiser_unmatched_function,
+        },
+        receipt::receipt::{
+            claim_receipt_remainder_function, create_receipt_fundraiser_function,
+            donate_for_receipt_function, withdraw_receipt_contribution_function,
+        },
+        rewards::rewards::{create_tiered_fundraiser_function, donate_for_tier_function},
+        round::round::{
+            claim_after_distribution_function, create_and_join_round_function,
+            distribute_matching_function, donate_in_round_function,
+        },
+        splits::splits::{claim_split_function, create_split_fundraiser_function},
+        swap::swap::donate_with_swap_function,
+        transfer_contribution::transfer_contribution::{
+            partial_transfer_contribution_function, transfer_contribution_function,
+            transfer_gated_contribution_function,
+        },
+        vesting::vesting::{create_vesting_fundraiser_function, withdraw_vested_function},
+        voting::voting::{
+            create_vote_fundraiser_function, submit_and_vote_function,
+            tally_first_milestone_function,
+        },
     };
 
     const PROGRAM_ID: Pubkey = crate::ID;
@@ -34,6 +120,7 @@ mod tests {
         pub token_program: Pubkey,
         pub system_program: Pubkey,
         pub fundraiser: (Pubkey, u8),
+        pub config: Pubkey,
         pub maker: Keypair,
         pub user: Option<Keypair>,
         pub user_ata: Option<Pubkey>,
@@ -44,6 +131,27 @@ mod tests {
         Pubkey::from(crate::ID)
     }
 
+    // token account amount sits at offset 64
+    pub fn token_balance(svm: &LiteSVM, token_account: &Pubkey) -> u64 {
+        let account = svm.get_account(token_account).unwrap();
+        u64::from_le_bytes(account.data[64..72].try_into().unwrap())
+    }
+
+    // unix_timestamp sits at offset 32 of the clock sysvar
+    pub fn current_unix_timestamp(svm: &LiteSVM) -> i64 {
+        let clock_account = svm.get_account(&clock::ID).unwrap();
+        i64::from_le_bytes(clock_account.data[32..40].try_into().unwrap())
+    }
+
+    // moves the clock sysvar forward, e.g. past a fundraiser deadline
+    pub fn warp_forward(svm: &mut LiteSVM, seconds: i64) {
+        let mut clock_account = svm.get_account(&clock::ID).unwrap();
+        let now = current_unix_timestamp(svm);
+
+        clock_account.data[32..40].copy_from_slice(&(now + seconds).to_le_bytes());
+        svm.set_account(clock::ID, clock_account).unwrap();
+    }
+
     fn setup() -> (LiteSVM, ReusableState) {
         let mut svm = LiteSVM::new();
         let payer = Keypair::new();
@@ -93,8 +201,61 @@ mod tests {
         svm.add_program(program_id(), bytes)
             .expect("Failed to add program");
 
+        // payer is the upgrade authority - program data: [tag 3, slot, Some(authority)]
+        let program_data =
+            Pubkey::find_program_address(&[crate::ID.as_ref()], &bpf_loader_upgradeable::ID).0;
+
+        svm.set_account(
+            program_data,
+            Account {
+                lamports: LAMPORTS_PER_SOL,
+                data: [
+                    3u32.to_le_bytes().to_vec(),
+                    0u64.to_le_bytes().to_vec(),
+                    vec![1],
+                    payer.pubkey().to_bytes().to_vec(),
+                ]
+                .concat(),
+                owner: bpf_loader_upgradeable::ID,
+                executable: false,
+                rent_epoch: 0,
+            },
+        )
+        .unwrap();
+
+        // program config without platform fee, payer is the admin
+        let config = Pubkey::find_program_address(&[b"config"], &crate::ID).0;
+
+        let config_data = ConfigData {
+            fee_bps: 0u16.to_le_bytes(),
+            fee_recipient: payer.pubkey().to_bytes(),
+            oracle_program: ORACLE_PROGRAM.to_bytes(),
+        };
+
+        let init_config_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new(config, false),
+                AccountMeta::new(system_program::ID, false),
+                AccountMeta::new_readonly(program_data, false),
+            ],
+            data: [
+                (FundraiserInstruction::InitConfig as u8)
+                    .to_le_bytes()
+                    .to_vec(),
+                config_data.to_bytes().to_vec(),
+            ]
+            .concat(),
+        };
+
+        let message = Message::new(&[init_config_ix], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+        svm.send_transaction(transaction).unwrap();
+
         let reusable_state = ReusableState {
             ata_program: ASSOCIATED_TOKEN_PROGRAM_ID.parse::<Pubkey>().unwrap(),
+            config,
             fundraiser,
             maker: payer,
             maker_ata,
@@ -122,4 +283,401 @@ mod tests {
         create_fundraiser_function(&mut svm, &reusable_state).unwrap();
         donate_function(&mut svm, &reusable_state).unwrap();
     }
+
+    #[test]
+    pub fn test_multi_mint_donate_instruction() {
+        let (mut svm, reusable_state) = setup();
+        let (second_mint, second_vault) =
+            create_multi_mint_fundraiser_function(&mut svm, &reusable_state).unwrap();
+        donate_second_mint_function(&mut svm, &reusable_state, &second_mint, &second_vault)
+            .unwrap();
+    }
+
+    #[test]
+    pub fn test_usd_mode_donate_instruction() {
+        let (mut svm, reusable_state) = setup();
+        let price_feed = create_usd_fundraiser_function(&mut svm, &reusable_state).unwrap();
+        donate_usd_function(&mut svm, &reusable_state, &price_feed).unwrap();
+    }
+
+    #[test]
+    pub fn test_donate_with_swap_instruction() {
+        let (mut svm, reusable_state) = setup();
+        create_fundraiser_function(&mut svm, &reusable_state).unwrap();
+        donate_with_swap_function(&mut svm, &reusable_state).unwrap();
+    }
+
+    #[test]
+    pub fn test_donate_with_matching_instruction() {
+        let (mut svm, reusable_state) = setup();
+        create_fundraiser_function(&mut svm, &reusable_state).unwrap();
+        let (matching, matching_vault) = fund_matching_function(&mut svm, &reusable_state).unwrap();
+        donate_with_matching_function(&mut svm, &reusable_state, &matching, &matching_vault)
+            .unwrap();
+    }
+
+    #[test]
+    pub fn test_donate_in_round_instruction() {
+        let (mut svm, reusable_state) = setup();
+        create_fundraiser_function(&mut svm, &reusable_state).unwrap();
+        let round = create_and_join_round_function(&mut svm, &reusable_state).unwrap();
+        donate_in_round_function(&mut svm, &reusable_state, &round).unwrap();
+    }
+
+    #[test]
+    pub fn test_release_tranche_instruction() {
+        let (mut svm, reusable_state) = setup();
+        let approver = create_milestone_fundraiser_function(&mut svm, &reusable_state).unwrap();
+        donate_function(&mut svm, &reusable_state).unwrap();
+        warp_forward(&mut svm, 4 * 24 * 60 * 60);
+        release_first_milestone_function(&mut svm, &reusable_state, &approver).unwrap();
+    }
+
+    #[test]
+    pub fn test_vote_milestone_instruction() {
+        let (mut svm, reusable_state) = setup();
+        create_vote_fundraiser_function(&mut svm, &reusable_state).unwrap();
+        let contributor = donate_function(&mut svm, &reusable_state).unwrap();
+        warp_forward(&mut svm, 4 * 24 * 60 * 60);
+        submit_and_vote_function(&mut svm, &reusable_state, &contributor).unwrap();
+        tally_first_milestone_function(&mut svm, &reusable_state).unwrap();
+    }
+
+    #[test]
+    pub fn test_abort_claim_remainder_instruction() {
+        let (mut svm, reusable_state) = setup();
+        create_vote_fundraiser_function(&mut svm, &reusable_state).unwrap();
+        let contributor = donate_function(&mut svm, &reusable_state).unwrap();
+        warp_forward(&mut svm, 4 * 24 * 60 * 60);
+        let proposal = abort_fundraiser_function(&mut svm, &reusable_state, &contributor).unwrap();
+        claim_remainder_function(&mut svm, &reusable_state, &contributor, &proposal).unwrap();
+    }
+
+    #[test]
+    pub fn test_withdraw_vested_instruction() {
+        let (mut svm, reusable_state) = setup();
+        create_vesting_fundraiser_function(&mut svm, &reusable_state).unwrap();
+        donate_function(&mut svm, &reusable_state).unwrap();
+        warp_forward(&mut svm, 5 * 24 * 60 * 60);
+        withdraw_vested_function(&mut svm, &reusable_state).unwrap();
+    }
+
+    #[test]
+    pub fn test_set_beneficiary_instruction() {
+        let (mut svm, reusable_state) = setup();
+        create_fundraiser_function(&mut svm, &reusable_state).unwrap();
+        set_beneficiary_function(&mut svm, &reusable_state).unwrap();
+    }
+
+    #[test]
+    pub fn test_claim_split_instruction() {
+        let (mut svm, reusable_state) = setup();
+        let sponsor_ata = create_split_fundraiser_function(&mut svm, &reusable_state).unwrap();
+        donate_function(&mut svm, &reusable_state).unwrap();
+        warp_forward(&mut svm, 4 * 24 * 60 * 60);
+        claim_split_function(&mut svm, &reusable_state, &sponsor_ata).unwrap();
+    }
+
+    #[test]
+    pub fn test_claim_with_fee_instruction() {
+        let (mut svm, reusable_state) = setup();
+        let fee_recipient = Keypair::new().pubkey();
+        update_config_function(&mut svm, &reusable_state, 500, &fee_recipient).unwrap();
+        let sponsor_ata = create_split_fundraiser_function(&mut svm, &reusable_state).unwrap();
+        // raising the fee later doesn't touch the live fundraiser
+        update_config_function(&mut svm, &reusable_state, 1_000, &fee_recipient).unwrap();
+        donate_function(&mut svm, &reusable_state).unwrap();
+        warp_forward(&mut svm, 4 * 24 * 60 * 60);
+        claim_with_fee_function(&mut svm, &reusable_state, &fee_recipient, &sponsor_ata).unwrap();
+    }
+
+    #[test]
+    pub fn test_global_pause_instruction() {
+        let (mut svm, reusable_state) = setup();
+        create_fundraiser_function(&mut svm, &reusable_state).unwrap();
+        set_paused_function(&mut svm, &reusable_state, true).unwrap();
+        donate_while_paused_function(&mut svm, &reusable_state).unwrap();
+        set_paused_function(&mut svm, &reusable_state, false).unwrap();
+        donate_function(&mut svm, &reusable_state).unwrap();
+    }
+
+    #[test]
+    pub fn test_pause_resume_instruction() {
+        let (mut svm, reusable_state) = setup();
+        create_fundraiser_function(&mut svm, &reusable_state).unwrap();
+        pause_and_resume_function(&mut svm, &reusable_state).unwrap();
+        donate_function(&mut svm, &reusable_state).unwrap();
+    }
+
+    #[test]
+    pub fn test_maker_transfer_instruction() {
+        let (mut svm, reusable_state) = setup();
+        create_fundraiser_function(&mut svm, &reusable_state).unwrap();
+        transfer_maker_function(&mut svm, &reusable_state).unwrap();
+    }
+
+    #[test]
+    pub fn test_multisig_maker_instruction() {
+        let (mut svm, reusable_state) = setup();
+        create_fundraiser_function(&mut svm, &reusable_state).unwrap();
+        let (multisig, members) = init_multisig_function(&mut svm, &reusable_state).unwrap();
+        multisig_pause_function(&mut svm, &reusable_state, &multisig, &members).unwrap();
+    }
+
+    #[test]
+    pub fn test_allowlist_donate_instruction() {
+        let (mut svm, reusable_state) = setup();
+        let (contributor, proof) =
+            create_allowlist_fundraiser_function(&mut svm, &reusable_state).unwrap();
+        donate_allowlisted_function(&mut svm, &reusable_state, &contributor, &proof).unwrap();
+    }
+
+    #[test]
+    pub fn test_attested_donate_instruction() {
+        let (mut svm, reusable_state) = setup();
+        let attestor = create_attested_fundraiser_function(&mut svm, &reusable_state).unwrap();
+        donate_attested_function(&mut svm, &reusable_state, &attestor).unwrap();
+    }
+
+    #[test]
+    pub fn test_denylist_donate_instruction() {
+        let (mut svm, reusable_state) = setup();
+        create_fundraiser_function(&mut svm, &reusable_state).unwrap();
+        let (denylist, sanctioned) = init_denylist_function(&mut svm, &reusable_state).unwrap();
+        donate_with_denylist_function(&mut svm, &reusable_state, &denylist, &sanctioned).unwrap();
+    }
+
+    #[test]
+    pub fn test_reward_tier_instruction() {
+        let (mut svm, reusable_state) = setup();
+        create_tiered_fundraiser_function(&mut svm, &reusable_state).unwrap();
+        donate_for_tier_function(&mut svm, &reusable_state).unwrap();
+    }
+
+    #[test]
+    pub fn test_receipt_donate_instruction() {
+        let (mut svm, reusable_state) = setup();
+        let receipt_mint = create_receipt_fundraiser_function(&mut svm, &reusable_state).unwrap();
+        donate_for_receipt_function(&mut svm, &reusable_state, &receipt_mint).unwrap();
+    }
+
+    #[test]
+    pub fn test_transfer_contribution_instruction() {
+        let (mut svm, reusable_state) = setup();
+        create_fundraiser_function(&mut svm, &reusable_state).unwrap();
+        let contributor = donate_function(&mut svm, &reusable_state).unwrap();
+        transfer_contribution_function(&mut svm, &reusable_state, &contributor).unwrap();
+    }
+
+    #[test]
+    pub fn test_withdraw_contribution_instruction() {
+        let (mut svm, reusable_state) = setup();
+        create_early_withdraw_fundraiser_function(&mut svm, &reusable_state).unwrap();
+        let contributor = donate_function(&mut svm, &reusable_state).unwrap();
+        withdraw_contribution_function(&mut svm, &reusable_state, &contributor).unwrap();
+    }
+
+    #[test]
+    pub fn test_pledge_and_settle_instruction() {
+        let (mut svm, reusable_state) = setup();
+        create_pledge_fundraiser_function(&mut svm, &reusable_state).unwrap();
+        pledge_and_settle_function(&mut svm, &reusable_state).unwrap();
+    }
+
+    #[test]
+    pub fn test_donate_for_instruction() {
+        let (mut svm, reusable_state) = setup();
+        create_fundraiser_function(&mut svm, &reusable_state).unwrap();
+        donate_for_function(&mut svm, &reusable_state).unwrap();
+    }
+
+    #[test]
+    pub fn test_pledge_default_instruction() {
+        let (mut svm, reusable_state) = setup();
+        create_pledge_fundraiser_function(&mut svm, &reusable_state).unwrap();
+        pledge_default_function(&mut svm, &reusable_state).unwrap();
+    }
+
+    #[test]
+    pub fn test_partial_transfer_contribution_instruction() {
+        let (mut svm, reusable_state) = setup();
+        let (second_mint, second_vault) =
+            create_multi_mint_fundraiser_function(&mut svm, &reusable_state).unwrap();
+        let contributor = donate_function(&mut svm, &reusable_state).unwrap();
+        partial_transfer_contribution_function(
+            &mut svm,
+            &reusable_state,
+            &contributor,
+            &second_mint,
+            &second_vault,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    pub fn test_distribute_matching_instruction() {
+        let (mut svm, reusable_state) = setup();
+        create_fundraiser_function(&mut svm, &reusable_state).unwrap();
+        let round = create_and_join_round_function(&mut svm, &reusable_state).unwrap();
+        donate_in_round_function(&mut svm, &reusable_state, &round).unwrap();
+        distribute_matching_function(&mut svm, &reusable_state, &round).unwrap();
+    }
+
+    #[test]
+    pub fn test_repropose_abort_instruction() {
+        let (mut svm, reusable_state) = setup();
+        create_vote_fundraiser_function(&mut svm, &reusable_state).unwrap();
+        let contributor = donate_function(&mut svm, &reusable_state).unwrap();
+        warp_forward(&mut svm, 4 * 24 * 60 * 60);
+        repropose_abort_function(&mut svm, &reusable_state, &contributor).unwrap();
+    }
+
+    #[test]
+    pub fn test_finalize_matching_instruction() {
+        let (mut svm, reusable_state) = setup();
+        create_fundraiser_function(&mut svm, &reusable_state).unwrap();
+        let (matching, matching_vault) = fund_matching_function(&mut svm, &reusable_state).unwrap();
+        donate_with_matching_function(&mut svm, &reusable_state, &matching, &matching_vault)
+            .unwrap();
+        finalize_matching_function(&mut svm, &reusable_state, &matching, &matching_vault).unwrap();
+    }
+
+    #[test]
+    pub fn test_claim_waits_for_round_distribution_instruction() {
+        let (mut svm, reusable_state) = setup();
+        let sponsor_ata = create_split_fundraiser_function(&mut svm, &reusable_state).unwrap();
+        let round = create_and_join_round_function(&mut svm, &reusable_state).unwrap();
+        donate_in_round_function(&mut svm, &reusable_state, &round).unwrap();
+        claim_after_distribution_function(&mut svm, &reusable_state, &round, &sponsor_ata).unwrap();
+    }
+
+    #[test]
+    pub fn test_transfer_allowlisted_contribution_instruction() {
+        let (mut svm, reusable_state) = setup();
+        let (contributor, proof) =
+            create_allowlist_fundraiser_function(&mut svm, &reusable_state).unwrap();
+        donate_allowlisted_function(&mut svm, &reusable_state, &contributor, &proof).unwrap();
+        transfer_gated_contribution_function(&mut svm, &reusable_state, &contributor).unwrap();
+    }
+
+    #[test]
+    pub fn test_transfer_attested_contribution_instruction() {
+        let (mut svm, reusable_state) = setup();
+        let attestor = create_attested_fundraiser_function(&mut svm, &reusable_state).unwrap();
+        let contributor = donate_attested_function(&mut svm, &reusable_state, &attestor).unwrap();
+        transfer_gated_contribution_function(&mut svm, &reusable_state, &contributor).unwrap();
+    }
+
+    #[test]
+    pub fn test_withdraw_contribution_burns_receipts_instruction() {
+        let (mut svm, reusable_state) = setup();
+        let receipt_mint = create_receipt_fundraiser_function(&mut svm, &reusable_state).unwrap();
+        let contributor =
+            donate_for_receipt_function(&mut svm, &reusable_state, &receipt_mint).unwrap();
+        withdraw_receipt_contribution_function(
+            &mut svm,
+            &reusable_state,
+            &receipt_mint,
+            &contributor,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    pub fn test_claim_remainder_burns_receipts_instruction() {
+        let (mut svm, reusable_state) = setup();
+        let receipt_mint = create_receipt_fundraiser_function(&mut svm, &reusable_state).unwrap();
+        let contributor =
+            donate_for_receipt_function(&mut svm, &reusable_state, &receipt_mint).unwrap();
+        warp_forward(&mut svm, 4 * 24 * 60 * 60);
+        let proposal = abort_fundraiser_function(&mut svm, &reusable_state, &contributor).unwrap();
+        claim_receipt_remainder_function(
+            &mut svm,
+            &reusable_state,
+            &receipt_mint,
+            &contributor,
+            &proposal,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    pub fn test_usd_stale_price_instruction() {
+        let (mut svm, reusable_state) = setup();
+        let price_feed = create_usd_fundraiser_function(&mut svm, &reusable_state).unwrap();
+        let now = current_unix_timestamp(&svm);
+        donate_usd_rejected_function(
+            &mut svm,
+            &reusable_state,
+            &price_feed,
+            1_000_000,
+            now - 61,
+            &ORACLE_PROGRAM,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    pub fn test_usd_future_price_instruction() {
+        let (mut svm, reusable_state) = setup();
+        let price_feed = create_usd_fundraiser_function(&mut svm, &reusable_state).unwrap();
+        let now = current_unix_timestamp(&svm);
+        donate_usd_rejected_function(
+            &mut svm,
+            &reusable_state,
+            &price_feed,
+            1_000_000,
+            now + 60,
+            &ORACLE_PROGRAM,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    pub fn test_usd_wide_confidence_instruction() {
+        let (mut svm, reusable_state) = setup();
+        let price_feed = create_usd_fundraiser_function(&mut svm, &reusable_state).unwrap();
+        let now = current_unix_timestamp(&svm);
+        // 3 cents on a 2 usd price is 1.5%, over the 1% allowed
+        donate_usd_rejected_function(
+            &mut svm,
+            &reusable_state,
+            &price_feed,
+            3_000_000,
+            now,
+            &ORACLE_PROGRAM,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    pub fn test_usd_foreign_price_feed_instruction() {
+        let (mut svm, reusable_state) = setup();
+        let price_feed = create_usd_fundraiser_function(&mut svm, &reusable_state).unwrap();
+        let now = current_unix_timestamp(&svm);
+        donate_usd_rejected_function(
+            &mut svm,
+            &reusable_state,
+            &price_feed,
+            1_000_000,
+            now,
+            &Pubkey::new_unique(),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    pub fn test_withdraw_to_denylisted_instruction() {
+        let (mut svm, reusable_state) = setup();
+        create_early_withdraw_fundraiser_function(&mut svm, &reusable_state).unwrap();
+        let contributor = donate_function(&mut svm, &reusable_state).unwrap();
+        withdraw_to_denylisted_function(&mut svm, &reusable_state, &contributor).unwrap();
+    }
+
+    #[test]
+    pub fn test_pledge_fundraiser_unmatched_instruction() {
+        let (mut svm, reusable_state) = setup();
+        create_pledge_fundraiser_function(&mut svm, &reusable_state).unwrap();
+        pledge_fundraiser_unmatched_function(&mut svm, &reusable_state).unwrap();
+    }
 }
@@ -0,0 +1,213 @@
+#[cfg(test)]
+pub mod multi_mint {
+    use core::fmt::Error;
+
+    use bytemuck::Zeroable;
+
+    use crate::instructions::{ContributeData, FundraiserInstruction, InitData};
+    use crate::state::{Contributor, Fundraiser, GOAL_MODE_TOKEN};
+    use crate::tests::tests::{program_id, ReusableState};
+    use litesvm::LiteSVM;
+    use litesvm_token::{CreateAssociatedTokenAccount, CreateMint, MintTo};
+    use solana_instruction::{AccountMeta, Instruction};
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_native_token::LAMPORTS_PER_SOL;
+    use solana_pubkey::Pubkey;
+    use solana_sdk_ids::sysvar::rent;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    // creates a fundraiser accepting the reusable mint plus a second one, returns (mint, vault) of the second
+    pub fn create_multi_mint_fundraiser_function(
+        svm: &mut LiteSVM,
+        state: &ReusableState,
+    ) -> Result<(Pubkey, Pubkey), Error> {
+        let ReusableState {
+            maker,
+            fundraiser,
+            mint,
+            vault,
+            system_program,
+            token_program,
+            ata_program,
+            config,
+            ..
+        } = state;
+
+        let second_mint = CreateMint::new(svm, maker)
+            .decimals(6)
+            .authority(&maker.pubkey())
+            .send()
+            .unwrap();
+
+        let second_vault =
+            spl_associated_token_account::get_associated_token_address(&fundraiser.0, &second_mint);
+
+        let amount_to_raise: u64 = 10_000_000_000; // 10k usdc - 6 decimals
+        let second_amount_to_raise: u64 = 5_000_000_000; // 5k usdt - 6 decimals
+
+        let initialize_data = InitData {
+            amount_to_raise: amount_to_raise.to_le_bytes(),
+            duration: 3u8.to_le_bytes(),
+            goal_mode: [GOAL_MODE_TOKEN],
+            max_price_age: 0u64.to_le_bytes(),
+            max_conf_bps: 0u16.to_le_bytes(),
+            max_amount_sendable: 4000_000_000u64.to_le_bytes(),
+            min_amount_sendable: 10_000_000u64.to_le_bytes(),
+            ..Zeroable::zeroed()
+        };
+
+        let make_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new(*mint, false),
+                AccountMeta::new(fundraiser.0, false),
+                AccountMeta::new(*vault, false),
+                AccountMeta::new(*system_program, false),
+                AccountMeta::new(*token_program, false),
+                AccountMeta::new(*ata_program, false),
+                AccountMeta::new(rent::ID, false),
+                AccountMeta::new_readonly(*config, false),
+                AccountMeta::new(second_mint, false),
+                AccountMeta::new(second_vault, false),
+            ],
+            data: [
+                (FundraiserInstruction::Initialize as u8)
+                    .to_le_bytes()
+                    .to_vec(),
+                initialize_data.to_bytes().to_vec(),
+                second_amount_to_raise.to_le_bytes().to_vec(),
+            ]
+            .concat(),
+        };
+
+        let message = Message::new(&[make_ix], Some(&maker.pubkey()));
+        let recent_blockhash = svm.latest_blockhash();
+
+        let transaction = Transaction::new(&[&maker], message, recent_blockhash);
+
+        let tx = svm.send_transaction(transaction).unwrap();
+
+        println!("\nMulti mint initialize transaction sucessfull");
+        println!("CUs Consumed: {}", tx.compute_units_consumed);
+
+        let fundraiser_account = svm.get_account(&fundraiser.0).unwrap();
+        let fundraiser_state =
+            bytemuck::from_bytes::<Fundraiser>(&fundraiser_account.data[..Fundraiser::LEN]);
+
+        assert_eq!(fundraiser_state.accepted_mints_count, [2]);
+        assert_eq!(
+            u64::from_le_bytes(fundraiser_state.amount_to_raise),
+            amount_to_raise + second_amount_to_raise
+        );
+
+        let accepted_mints = Fundraiser::accepted_mints(&fundraiser_account.data);
+        assert_eq!(accepted_mints[1].mint, second_mint.to_bytes());
+        assert_eq!(accepted_mints[1].vault, second_vault.to_bytes());
+
+        Ok((second_mint, second_vault))
+    }
+
+    pub fn donate_second_mint_function(
+        svm: &mut LiteSVM,
+        state: &ReusableState,
+        second_mint: &Pubkey,
+        second_vault: &Pubkey,
+    ) -> Result<(), Error> {
+        let ReusableState {
+            maker,
+            fundraiser,
+            system_program,
+            token_program,
+            ata_program,
+            config,
+            ..
+        } = state;
+
+        let amount_to_donate: u64 = 100_000_000; // 100 usdt - 6 decimals
+
+        let contributor = Keypair::new();
+
+        svm.airdrop(&contributor.pubkey(), 10 * LAMPORTS_PER_SOL)
+            .expect("Airdrop failed");
+
+        let contributor_ata = CreateAssociatedTokenAccount::new(svm, &contributor, second_mint)
+            .owner(&contributor.pubkey())
+            .send()
+            .unwrap();
+
+        MintTo::new(svm, maker, second_mint, &contributor_ata, 1_000_000_000)
+            .send()
+            .unwrap();
+
+        let contributor_pda = Pubkey::find_program_address(
+            &[
+                b"contributor".as_ref(),
+                fundraiser.0.as_ref(),
+                contributor.pubkey().as_ref(),
+            ],
+            &program_id(),
+        );
+
+        let contribute_data = ContributeData {
+            amount: amount_to_donate.to_le_bytes(),
+            min_out: 0u64.to_le_bytes(),
+            swap: [0],
+            swap_accounts: [0],
+        };
+
+        let donate_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(contributor.pubkey(), true),
+                AccountMeta::new(*second_mint, false),
+                AccountMeta::new(fundraiser.0, false),
+                AccountMeta::new(contributor_pda.0, false),
+                AccountMeta::new(contributor_ata, false),
+                AccountMeta::new(*second_vault, false),
+                AccountMeta::new(*system_program, false),
+                AccountMeta::new(*token_program, false),
+                AccountMeta::new(*ata_program, false),
+                AccountMeta::new(rent::ID, false),
+                AccountMeta::new_readonly(*config, false),
+            ],
+            data: [
+                (FundraiserInstruction::Deposit as u8)
+                    .to_le_bytes()
+                    .to_vec(),
+                contribute_data.to_bytes().to_vec(),
+            ]
+            .concat(),
+        };
+
+        let message = Message::new(&[donate_ix], Some(&contributor.pubkey()));
+        let recent_blockhash = svm.latest_blockhash();
+
+        let transaction = Transaction::new(&[&contributor], message, recent_blockhash);
+
+        let tx = svm.send_transaction(transaction).unwrap();
+
+        println!("\nSecond mint donate transaction sucessfull");
+        println!("CUs Consumed: {}", tx.compute_units_consumed);
+
+        let contributor_account = svm.get_account(&contributor_pda.0).unwrap();
+        let contributor_state = bytemuck::from_bytes::<Contributor>(&contributor_account.data);
+
+        assert_eq!(u64::from_le_bytes(contributor_state.amounts[0]), 0);
+        assert_eq!(
+            u64::from_le_bytes(contributor_state.amounts[1]),
+            amount_to_donate
+        );
+
+        let fundraiser_account = svm.get_account(&fundraiser.0).unwrap();
+        let accepted_mints = Fundraiser::accepted_mints(&fundraiser_account.data);
+        assert_eq!(
+            u64::from_le_bytes(accepted_mints[1].current_amount),
+            amount_to_donate
+        );
+
+        Ok(())
+    }
+}
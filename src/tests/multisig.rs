@@ -0,0 +1,135 @@
+#[cfg(test)]
+pub mod multisig {
+    use core::fmt::Error;
+
+    use crate::instructions::{FundraiserInstruction, MultisigData};
+    use crate::state::{Fundraiser, MAX_MULTISIG_SIGNERS};
+    use crate::tests::tests::{program_id, ReusableState};
+    use litesvm::LiteSVM;
+    use solana_instruction::{AccountMeta, Instruction};
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_pubkey::Pubkey;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    // maker hands the fundraiser to a 2-of-3 multisig, returns (multisig, members)
+    pub fn init_multisig_function(
+        svm: &mut LiteSVM,
+        state: &ReusableState,
+    ) -> Result<(Pubkey, [Keypair; 3]), Error> {
+        let ReusableState {
+            maker,
+            fundraiser,
+            system_program,
+            ..
+        } = state;
+
+        let members = [Keypair::new(), Keypair::new(), Keypair::new()];
+
+        let multisig =
+            Pubkey::find_program_address(&[b"multisig", fundraiser.0.as_ref()], &program_id()).0;
+
+        let mut signers = [[0u8; 32]; MAX_MULTISIG_SIGNERS];
+        for (signer, member) in signers.iter_mut().zip(members.iter()) {
+            *signer = member.pubkey().to_bytes();
+        }
+
+        let multisig_data = MultisigData {
+            threshold: [2],
+            signers_count: [3],
+            signers,
+        };
+
+        let init_multisig_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new(fundraiser.0, false),
+                AccountMeta::new(multisig, false),
+                AccountMeta::new(*system_program, false),
+            ],
+            data: [
+                (FundraiserInstruction::InitMultisig as u8)
+                    .to_le_bytes()
+                    .to_vec(),
+                multisig_data.to_bytes().to_vec(),
+            ]
+            .concat(),
+        };
+
+        let message = Message::new(&[init_multisig_ix], Some(&maker.pubkey()));
+        let recent_blockhash = svm.latest_blockhash();
+
+        let transaction = Transaction::new(&[&maker], message, recent_blockhash);
+
+        let tx = svm.send_transaction(transaction).unwrap();
+
+        println!("\nInit multisig transaction sucessfull");
+        println!("CUs Consumed: {}", tx.compute_units_consumed);
+
+        let fundraiser_account = svm.get_account(&fundraiser.0).unwrap();
+        let fundraiser_state =
+            bytemuck::from_bytes::<Fundraiser>(&fundraiser_account.data[..Fundraiser::LEN]);
+        assert_eq!(fundraiser_state.maker, multisig.to_bytes());
+        assert_eq!(fundraiser_state.pending_maker, [0u8; 32]);
+
+        Ok((multisig, members))
+    }
+
+    // one member alone can't pause the fundraiser, two can
+    pub fn multisig_pause_function(
+        svm: &mut LiteSVM,
+        state: &ReusableState,
+        multisig: &Pubkey,
+        members: &[Keypair; 3],
+    ) -> Result<(), Error> {
+        let ReusableState {
+            maker, fundraiser, ..
+        } = state;
+
+        let pause_ix = |signers: &[&Keypair]| {
+            let mut accounts = vec![
+                AccountMeta::new_readonly(*multisig, false),
+                AccountMeta::new(fundraiser.0, false),
+            ];
+            accounts.extend(
+                signers
+                    .iter()
+                    .map(|signer| AccountMeta::new_readonly(signer.pubkey(), true)),
+            );
+
+            Instruction {
+                program_id: program_id(),
+                accounts,
+                data: vec![FundraiserInstruction::Pause as u8],
+            }
+        };
+
+        let message = Message::new(&[pause_ix(&[&members[0]])], Some(&maker.pubkey()));
+        let transaction = Transaction::new(&[maker, &members[0]], message, svm.latest_blockhash());
+        assert!(svm.send_transaction(transaction).is_err());
+
+        let message = Message::new(
+            &[pause_ix(&[&members[0], &members[2]])],
+            Some(&maker.pubkey()),
+        );
+        let transaction = Transaction::new(
+            &[maker, &members[0], &members[2]],
+            message,
+            svm.latest_blockhash(),
+        );
+
+        let tx = svm.send_transaction(transaction).unwrap();
+
+        println!("\nMultisig pause transaction sucessfull");
+        println!("CUs Consumed: {}", tx.compute_units_consumed);
+
+        let fundraiser_account = svm.get_account(&fundraiser.0).unwrap();
+        let fundraiser_state =
+            bytemuck::from_bytes::<Fundraiser>(&fundraiser_account.data[..Fundraiser::LEN]);
+        assert!(fundraiser_state.is_paused());
+
+        Ok(())
+    }
+}
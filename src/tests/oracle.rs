@@ -0,0 +1,346 @@
+#[cfg(test)]
+pub mod oracle {
+    use core::fmt::Error;
+
+    use bytemuck::Zeroable;
+
+    use crate::instructions::{ContributeData, FundraiserInstruction, InitData};
+    use crate::state::{Contributor, Fundraiser, PriceFeed, GOAL_MODE_USD, PRICE_FEED_MAGIC};
+    use crate::tests::tests::{current_unix_timestamp, program_id, ReusableState};
+    use litesvm::LiteSVM;
+    use litesvm_token::{CreateAssociatedTokenAccount, MintTo};
+    use solana_account::Account;
+    use solana_instruction::{AccountMeta, Instruction};
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_native_token::LAMPORTS_PER_SOL;
+    use solana_pubkey::Pubkey;
+    use solana_sdk_ids::sysvar::rent;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    // program the config trusts to own price feeds
+    pub const ORACLE_PROGRAM: Pubkey = Pubkey::new_from_array([7u8; 32]);
+
+    // writes a mock pyth-style price account, returns its address
+    pub fn set_price_feed(svm: &mut LiteSVM, price: i64, conf: u64, publish_time: i64) -> Pubkey {
+        let price_feed = Pubkey::new_unique();
+
+        write_price_feed(svm, &price_feed, price, conf, publish_time, &ORACLE_PROGRAM);
+
+        price_feed
+    }
+
+    // (re)writes the mock price account at `price_feed` under `owner`
+    pub fn write_price_feed(
+        svm: &mut LiteSVM,
+        price_feed: &Pubkey,
+        price: i64,
+        conf: u64,
+        publish_time: i64,
+        owner: &Pubkey,
+    ) {
+        let price_feed_data = PriceFeed {
+            magic: PRICE_FEED_MAGIC.to_le_bytes(),
+            expo: (-8i32).to_le_bytes(),
+            price: price.to_le_bytes(),
+            conf: conf.to_le_bytes(),
+            publish_time: publish_time.to_le_bytes(),
+        };
+
+        svm.set_account(
+            *price_feed,
+            Account {
+                lamports: LAMPORTS_PER_SOL,
+                data: price_feed_data.to_bytes().to_vec(),
+                owner: *owner,
+                executable: false,
+                rent_epoch: 0,
+            },
+        )
+        .unwrap();
+    }
+
+    pub fn create_usd_fundraiser_function(
+        svm: &mut LiteSVM,
+        state: &ReusableState,
+    ) -> Result<Pubkey, Error> {
+        let ReusableState {
+            maker,
+            fundraiser,
+            mint,
+            vault,
+            system_program,
+            token_program,
+            ata_program,
+            config,
+            ..
+        } = state;
+
+        // 1 token = 2 usd, conf of 1 cent
+        let now = current_unix_timestamp(svm);
+        let price_feed = set_price_feed(svm, 200_000_000, 1_000_000, now);
+
+        let initialize_data = InitData {
+            amount_to_raise: 10_000_000_000u64.to_le_bytes(), // 10k usd
+            duration: 3u8.to_le_bytes(),
+            goal_mode: [GOAL_MODE_USD],
+            max_price_age: 60u64.to_le_bytes(),
+            max_conf_bps: 100u16.to_le_bytes(), // 1%
+            max_amount_sendable: 4000_000_000u64.to_le_bytes(),
+            min_amount_sendable: 10_000_000u64.to_le_bytes(),
+            ..Zeroable::zeroed()
+        };
+
+        let make_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new(*mint, false),
+                AccountMeta::new(fundraiser.0, false),
+                AccountMeta::new(*vault, false),
+                AccountMeta::new(*system_program, false),
+                AccountMeta::new(*token_program, false),
+                AccountMeta::new(*ata_program, false),
+                AccountMeta::new(rent::ID, false),
+                AccountMeta::new_readonly(*config, false),
+                AccountMeta::new_readonly(price_feed, false),
+            ],
+            data: [
+                (FundraiserInstruction::Initialize as u8)
+                    .to_le_bytes()
+                    .to_vec(),
+                initialize_data.to_bytes().to_vec(),
+            ]
+            .concat(),
+        };
+
+        let message = Message::new(&[make_ix], Some(&maker.pubkey()));
+        let recent_blockhash = svm.latest_blockhash();
+
+        let transaction = Transaction::new(&[&maker], message, recent_blockhash);
+
+        let tx = svm.send_transaction(transaction).unwrap();
+
+        println!("\nUsd initialize transaction sucessfull");
+        println!("CUs Consumed: {}", tx.compute_units_consumed);
+
+        // the usd goal is the only one, the primary mint has no token goal
+        let fundraiser_account = svm.get_account(&fundraiser.0).unwrap();
+        let accepted_mints = Fundraiser::accepted_mints(&fundraiser_account.data);
+        assert_eq!(u64::from_le_bytes(accepted_mints[0].amount_to_raise), 0);
+
+        Ok(price_feed)
+    }
+
+    pub fn donate_usd_function(
+        svm: &mut LiteSVM,
+        state: &ReusableState,
+        price_feed: &Pubkey,
+    ) -> Result<(), Error> {
+        let ReusableState {
+            maker,
+            fundraiser,
+            mint,
+            vault,
+            system_program,
+            token_program,
+            ata_program,
+            config,
+            ..
+        } = state;
+
+        let amount_to_donate: u64 = 100_000_000; // 100 tokens - 6 decimals
+
+        let contributor = Keypair::new();
+
+        svm.airdrop(&contributor.pubkey(), 10 * LAMPORTS_PER_SOL)
+            .expect("Airdrop failed");
+
+        let contributor_ata = CreateAssociatedTokenAccount::new(svm, &contributor, mint)
+            .owner(&contributor.pubkey())
+            .send()
+            .unwrap();
+
+        MintTo::new(svm, maker, mint, &contributor_ata, 1_000_000_000)
+            .send()
+            .unwrap();
+
+        let contributor_pda = Pubkey::find_program_address(
+            &[
+                b"contributor".as_ref(),
+                fundraiser.0.as_ref(),
+                contributor.pubkey().as_ref(),
+            ],
+            &program_id(),
+        );
+
+        let contribute_data = ContributeData {
+            amount: amount_to_donate.to_le_bytes(),
+            min_out: 0u64.to_le_bytes(),
+            swap: [0],
+            swap_accounts: [0],
+        };
+
+        let donate_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(contributor.pubkey(), true),
+                AccountMeta::new(*mint, false),
+                AccountMeta::new(fundraiser.0, false),
+                AccountMeta::new(contributor_pda.0, false),
+                AccountMeta::new(contributor_ata, false),
+                AccountMeta::new(*vault, false),
+                AccountMeta::new(*system_program, false),
+                AccountMeta::new(*token_program, false),
+                AccountMeta::new(*ata_program, false),
+                AccountMeta::new(rent::ID, false),
+                AccountMeta::new_readonly(*config, false),
+                AccountMeta::new_readonly(*price_feed, false),
+            ],
+            data: [
+                (FundraiserInstruction::Deposit as u8)
+                    .to_le_bytes()
+                    .to_vec(),
+                contribute_data.to_bytes().to_vec(),
+            ]
+            .concat(),
+        };
+
+        let message = Message::new(&[donate_ix], Some(&contributor.pubkey()));
+        let recent_blockhash = svm.latest_blockhash();
+
+        let transaction = Transaction::new(&[&contributor], message, recent_blockhash);
+
+        let tx = svm.send_transaction(transaction).unwrap();
+
+        println!("\nUsd donate transaction sucessfull");
+        println!("CUs Consumed: {}", tx.compute_units_consumed);
+
+        // 100 tokens at 2 usd each
+        let contributor_account = svm.get_account(&contributor_pda.0).unwrap();
+        let contributor_state = bytemuck::from_bytes::<Contributor>(&contributor_account.data);
+        assert_eq!(u64::from_le_bytes(contributor_state.amount), 200_000_000);
+        assert_eq!(
+            u64::from_le_bytes(contributor_state.amounts[0]),
+            amount_to_donate
+        );
+
+        let fundraiser_account = svm.get_account(&fundraiser.0).unwrap();
+        let fundraiser_state =
+            bytemuck::from_bytes::<Fundraiser>(&fundraiser_account.data[..Fundraiser::LEN]);
+        assert_eq!(
+            u64::from_le_bytes(fundraiser_state.current_amount),
+            200_000_000
+        );
+
+        Ok(())
+    }
+
+    // the price feed is rewritten with `conf`, `publish_time` and `owner` - a 100 token deposit
+    // is rejected, then goes through once the feed is fresh again
+    pub fn donate_usd_rejected_function(
+        svm: &mut LiteSVM,
+        state: &ReusableState,
+        price_feed: &Pubkey,
+        conf: u64,
+        publish_time: i64,
+        owner: &Pubkey,
+    ) -> Result<(), Error> {
+        let ReusableState {
+            maker,
+            fundraiser,
+            mint,
+            vault,
+            system_program,
+            token_program,
+            ata_program,
+            config,
+            ..
+        } = state;
+
+        let contributor = Keypair::new();
+
+        svm.airdrop(&contributor.pubkey(), 10 * LAMPORTS_PER_SOL)
+            .expect("Airdrop failed");
+
+        let contributor_ata = CreateAssociatedTokenAccount::new(svm, &contributor, mint)
+            .owner(&contributor.pubkey())
+            .send()
+            .unwrap();
+
+        MintTo::new(svm, maker, mint, &contributor_ata, 1_000_000_000)
+            .send()
+            .unwrap();
+
+        let contributor_pda = Pubkey::find_program_address(
+            &[
+                b"contributor".as_ref(),
+                fundraiser.0.as_ref(),
+                contributor.pubkey().as_ref(),
+            ],
+            &program_id(),
+        );
+
+        let contribute_data = ContributeData {
+            amount: 100_000_000u64.to_le_bytes(),
+            min_out: 0u64.to_le_bytes(),
+            swap: [0],
+            swap_accounts: [0],
+        };
+
+        let donate_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(contributor.pubkey(), true),
+                AccountMeta::new(*mint, false),
+                AccountMeta::new(fundraiser.0, false),
+                AccountMeta::new(contributor_pda.0, false),
+                AccountMeta::new(contributor_ata, false),
+                AccountMeta::new(*vault, false),
+                AccountMeta::new(*system_program, false),
+                AccountMeta::new(*token_program, false),
+                AccountMeta::new(*ata_program, false),
+                AccountMeta::new(rent::ID, false),
+                AccountMeta::new_readonly(*config, false),
+                AccountMeta::new_readonly(*price_feed, false),
+            ],
+            data: [
+                (FundraiserInstruction::Deposit as u8)
+                    .to_le_bytes()
+                    .to_vec(),
+                contribute_data.to_bytes().to_vec(),
+            ]
+            .concat(),
+        };
+
+        write_price_feed(svm, price_feed, 200_000_000, conf, publish_time, owner);
+
+        let message = Message::new(&[donate_ix.clone()], Some(&contributor.pubkey()));
+        let transaction = Transaction::new(&[&contributor], message, svm.latest_blockhash());
+        assert!(svm.send_transaction(transaction).is_err());
+        assert!(svm.get_account(&contributor_pda.0).is_none());
+
+        // 1 token = 2 usd, conf of 1 cent, published now
+        let now = current_unix_timestamp(svm);
+        write_price_feed(
+            svm,
+            price_feed,
+            200_000_000,
+            1_000_000,
+            now,
+            &ORACLE_PROGRAM,
+        );
+
+        svm.expire_blockhash();
+        let message = Message::new(&[donate_ix], Some(&contributor.pubkey()));
+        let transaction = Transaction::new(&[&contributor], message, svm.latest_blockhash());
+        svm.send_transaction(transaction).unwrap();
+
+        let contributor_account = svm.get_account(&contributor_pda.0).unwrap();
+        let contributor_state = bytemuck::from_bytes::<Contributor>(&contributor_account.data);
+        assert_eq!(u64::from_le_bytes(contributor_state.amount), 200_000_000);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,196 @@
+#[cfg(test)]
+pub mod pause {
+    use core::fmt::Error;
+
+    use crate::instructions::{ContributeData, FundraiserInstruction};
+    use crate::state::{Config, Fundraiser};
+    use crate::tests::tests::{program_id, warp_forward, ReusableState};
+    use litesvm::LiteSVM;
+    use litesvm_token::{CreateAssociatedTokenAccount, MintTo};
+    use solana_instruction::{AccountMeta, Instruction};
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_native_token::LAMPORTS_PER_SOL;
+    use solana_pubkey::Pubkey;
+    use solana_sdk_ids::sysvar::rent;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    pub fn set_paused_function(
+        svm: &mut LiteSVM,
+        state: &ReusableState,
+        paused: bool,
+    ) -> Result<(), Error> {
+        let ReusableState { maker, config, .. } = state;
+
+        let set_paused_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new_readonly(maker.pubkey(), true),
+                AccountMeta::new(*config, false),
+            ],
+            data: vec![FundraiserInstruction::SetPaused as u8, paused as u8],
+        };
+
+        let message = Message::new(&[set_paused_ix], Some(&maker.pubkey()));
+        let recent_blockhash = svm.latest_blockhash();
+
+        let transaction = Transaction::new(&[&maker], message, recent_blockhash);
+
+        let tx = svm.send_transaction(transaction).unwrap();
+
+        println!("\nSet paused transaction sucessfull");
+        println!("CUs Consumed: {}", tx.compute_units_consumed);
+
+        let config_account = svm.get_account(config).unwrap();
+        let config_state = bytemuck::from_bytes::<Config>(&config_account.data);
+        assert_eq!(config_state.is_paused(), paused);
+
+        Ok(())
+    }
+
+    // deposits are rejected while the program is paused
+    pub fn donate_while_paused_function(
+        svm: &mut LiteSVM,
+        state: &ReusableState,
+    ) -> Result<(), Error> {
+        let ReusableState {
+            maker,
+            fundraiser,
+            mint,
+            vault,
+            system_program,
+            token_program,
+            ata_program,
+            config,
+            ..
+        } = state;
+
+        let contributor = Keypair::new();
+
+        svm.airdrop(&contributor.pubkey(), 10 * LAMPORTS_PER_SOL)
+            .expect("Airdrop failed");
+
+        let contributor_ata = CreateAssociatedTokenAccount::new(svm, &contributor, mint)
+            .owner(&contributor.pubkey())
+            .send()
+            .unwrap();
+
+        MintTo::new(svm, maker, mint, &contributor_ata, 1_000_000_000)
+            .send()
+            .unwrap();
+
+        let contributor_pda = Pubkey::find_program_address(
+            &[
+                b"contributor".as_ref(),
+                fundraiser.0.as_ref(),
+                contributor.pubkey().as_ref(),
+            ],
+            &program_id(),
+        );
+
+        let contribute_data = ContributeData {
+            amount: 100_000_000u64.to_le_bytes(),
+            min_out: 0u64.to_le_bytes(),
+            swap: [0],
+            swap_accounts: [0],
+        };
+
+        let donate_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(contributor.pubkey(), true),
+                AccountMeta::new(*mint, false),
+                AccountMeta::new(fundraiser.0, false),
+                AccountMeta::new(contributor_pda.0, false),
+                AccountMeta::new(contributor_ata, false),
+                AccountMeta::new(*vault, false),
+                AccountMeta::new(*system_program, false),
+                AccountMeta::new(*token_program, false),
+                AccountMeta::new(*ata_program, false),
+                AccountMeta::new(rent::ID, false),
+                AccountMeta::new_readonly(*config, false),
+            ],
+            data: [
+                (FundraiserInstruction::Deposit as u8)
+                    .to_le_bytes()
+                    .to_vec(),
+                contribute_data.to_bytes().to_vec(),
+            ]
+            .concat(),
+        };
+
+        let message = Message::new(&[donate_ix], Some(&contributor.pubkey()));
+        let recent_blockhash = svm.latest_blockhash();
+
+        let transaction = Transaction::new(&[&contributor], message, recent_blockhash);
+
+        assert!(svm.send_transaction(transaction).is_err());
+
+        Ok(())
+    }
+
+    // maker pauses for a day and gets that day added back to the deadline
+    pub fn pause_and_resume_function(
+        svm: &mut LiteSVM,
+        state: &ReusableState,
+    ) -> Result<(), Error> {
+        let ReusableState {
+            maker, fundraiser, ..
+        } = state;
+
+        let end_time = {
+            let fundraiser_account = svm.get_account(&fundraiser.0).unwrap();
+            bytemuck::from_bytes::<Fundraiser>(&fundraiser_account.data[..Fundraiser::LEN])
+                .end_time()
+        };
+
+        let pause_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new_readonly(maker.pubkey(), true),
+                AccountMeta::new(fundraiser.0, false),
+            ],
+            data: vec![FundraiserInstruction::Pause as u8],
+        };
+
+        let message = Message::new(&[pause_ix], Some(&maker.pubkey()));
+        let recent_blockhash = svm.latest_blockhash();
+
+        let transaction = Transaction::new(&[&maker], message, recent_blockhash);
+
+        let tx = svm.send_transaction(transaction).unwrap();
+
+        println!("\nPause transaction sucessfull");
+        println!("CUs Consumed: {}", tx.compute_units_consumed);
+
+        warp_forward(svm, 24 * 60 * 60);
+
+        let resume_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new_readonly(maker.pubkey(), true),
+                AccountMeta::new(fundraiser.0, false),
+            ],
+            data: vec![FundraiserInstruction::Resume as u8, 1],
+        };
+
+        let message = Message::new(&[resume_ix], Some(&maker.pubkey()));
+        let recent_blockhash = svm.latest_blockhash();
+
+        let transaction = Transaction::new(&[&maker], message, recent_blockhash);
+
+        let tx = svm.send_transaction(transaction).unwrap();
+
+        println!("\nResume transaction sucessfull");
+        println!("CUs Consumed: {}", tx.compute_units_consumed);
+
+        let fundraiser_account = svm.get_account(&fundraiser.0).unwrap();
+        let fundraiser_state =
+            bytemuck::from_bytes::<Fundraiser>(&fundraiser_account.data[..Fundraiser::LEN]);
+        assert!(!fundraiser_state.is_paused());
+        assert_eq!(fundraiser_state.end_time(), end_time + 24 * 60 * 60);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,457 @@
+#[cfg(test)]
+pub mod pledge {
+    use core::fmt::Error;
+
+    use bytemuck::Zeroable;
+
+    use crate::instructions::{FundraiserInstruction, InitData, MatchingData, RoundData};
+    use crate::state::{Contributor, Fundraiser, GOAL_MODE_TOKEN};
+    use crate::tests::tests::{
+        current_unix_timestamp, program_id, token_balance, warp_forward, ReusableState,
+    };
+    use litesvm::LiteSVM;
+    use litesvm_token::{spl_token, CreateAssociatedTokenAccount, MintTo};
+    use solana_instruction::{AccountMeta, Instruction};
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_native_token::LAMPORTS_PER_SOL;
+    use solana_pubkey::Pubkey;
+    use solana_sdk_ids::sysvar::rent;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    // 100 usdc goal raised through pledges
+    pub fn create_pledge_fundraiser_function(
+        svm: &mut LiteSVM,
+        state: &ReusableState,
+    ) -> Result<(), Error> {
+        let ReusableState {
+            maker,
+            fundraiser,
+            mint,
+            vault,
+            system_program,
+            token_program,
+            ata_program,
+            config,
+            ..
+        } = state;
+
+        let initialize_data = InitData {
+            amount_to_raise: 100_000_000u64.to_le_bytes(),
+            duration: 3u8.to_le_bytes(),
+            goal_mode: [GOAL_MODE_TOKEN],
+            pledge_mode: [1],
+            ..Zeroable::zeroed()
+        };
+
+        let make_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new(*mint, false),
+                AccountMeta::new(fundraiser.0, false),
+                AccountMeta::new(*vault, false),
+                AccountMeta::new(*system_program, false),
+                AccountMeta::new(*token_program, false),
+                AccountMeta::new(*ata_program, false),
+                AccountMeta::new(rent::ID, false),
+                AccountMeta::new_readonly(*config, false),
+            ],
+            data: [
+                (FundraiserInstruction::Initialize as u8)
+                    .to_le_bytes()
+                    .to_vec(),
+                initialize_data.to_bytes().to_vec(),
+            ]
+            .concat(),
+        };
+
+        let message = Message::new(&[make_ix], Some(&maker.pubkey()));
+        let recent_blockhash = svm.latest_blockhash();
+
+        let transaction = Transaction::new(&[&maker], message, recent_blockhash);
+
+        let tx = svm.send_transaction(transaction).unwrap();
+
+        println!("\nPledge initialize transaction sucessfull");
+        println!("CUs Consumed: {}", tx.compute_units_consumed);
+
+        let fundraiser_account = svm.get_account(&fundraiser.0).unwrap();
+        let fundraiser_state =
+            bytemuck::from_bytes::<Fundraiser>(&fundraiser_account.data[..Fundraiser::LEN]);
+        assert!(fundraiser_state.is_pledge_mode());
+
+        Ok(())
+    }
+
+    // a contributor pledges the whole goal, funds stay in their ata until settle pulls them
+    pub fn pledge_and_settle_function(
+        svm: &mut LiteSVM,
+        state: &ReusableState,
+    ) -> Result<(), Error> {
+        let ReusableState {
+            maker,
+            fundraiser,
+            mint,
+            vault,
+            system_program,
+            token_program,
+            config,
+            ..
+        } = state;
+
+        let contributor = Keypair::new();
+
+        svm.airdrop(&contributor.pubkey(), 10 * LAMPORTS_PER_SOL)
+            .expect("Airdrop failed");
+
+        let contributor_ata = CreateAssociatedTokenAccount::new(svm, &contributor, mint)
+            .owner(&contributor.pubkey())
+            .send()
+            .unwrap();
+
+        MintTo::new(svm, maker, mint, &contributor_ata, 1_000_000_000)
+            .send()
+            .unwrap();
+
+        let contributor_pda = Pubkey::find_program_address(
+            &[
+                b"contributor".as_ref(),
+                fundraiser.0.as_ref(),
+                contributor.pubkey().as_ref(),
+            ],
+            &program_id(),
+        );
+
+        let pledge_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(contributor.pubkey(), true),
+                AccountMeta::new(fundraiser.0, false),
+                AccountMeta::new(contributor_pda.0, false),
+                AccountMeta::new(contributor_ata, false),
+                AccountMeta::new_readonly(*system_program, false),
+                AccountMeta::new_readonly(*token_program, false),
+                AccountMeta::new_readonly(*config, false),
+            ],
+            data: [
+                vec![FundraiserInstruction::Pledge as u8],
+                100_000_000u64.to_le_bytes().to_vec(),
+            ]
+            .concat(),
+        };
+
+        let message = Message::new(&[pledge_ix], Some(&contributor.pubkey()));
+        let recent_blockhash = svm.latest_blockhash();
+
+        let transaction = Transaction::new(&[&contributor], message, recent_blockhash);
+
+        let tx = svm.send_transaction(transaction).unwrap();
+
+        println!("\nPledge transaction sucessfull");
+        println!("CUs Consumed: {}", tx.compute_units_consumed);
+
+        // pledged, not moved
+        assert_eq!(token_balance(svm, &contributor_ata), 1_000_000_000);
+        assert_eq!(token_balance(svm, vault), 0);
+
+        warp_forward(svm, 4 * 24 * 60 * 60);
+
+        // permissionless - anyone can pay for the settle
+        let settler = Keypair::new();
+        svm.airdrop(&settler.pubkey(), LAMPORTS_PER_SOL)
+            .expect("Airdrop failed");
+
+        let settle_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(fundraiser.0, false),
+                AccountMeta::new_readonly(contributor.pubkey(), false),
+                AccountMeta::new(contributor_pda.0, false),
+                AccountMeta::new(contributor_ata, false),
+                AccountMeta::new(*vault, false),
+                AccountMeta::new_readonly(*token_program, false),
+            ],
+            data: vec![FundraiserInstruction::Settle as u8],
+        };
+
+        let message = Message::new(&[settle_ix], Some(&settler.pubkey()));
+        let recent_blockhash = svm.latest_blockhash();
+
+        let transaction = Transaction::new(&[&settler], message, recent_blockhash);
+
+        let tx = svm.send_transaction(transaction).unwrap();
+
+        println!("\nSettle transaction sucessfull");
+        println!("CUs Consumed: {}", tx.compute_units_consumed);
+
+        assert_eq!(token_balance(svm, &contributor_ata), 900_000_000);
+        assert_eq!(token_balance(svm, vault), 100_000_000);
+
+        let contributor_account = svm.get_account(&contributor_pda.0).unwrap();
+        let contributor_state = bytemuck::from_bytes::<Contributor>(&contributor_account.data);
+        assert_eq!(u64::from_le_bytes(contributor_state.amount), 100_000_000);
+        assert_eq!(u64::from_le_bytes(contributor_state.pledged), 0);
+
+        let fundraiser_account = svm.get_account(&fundraiser.0).unwrap();
+        let fundraiser_state =
+            bytemuck::from_bytes::<Fundraiser>(&fundraiser_account.data[..Fundraiser::LEN]);
+        assert_eq!(u64::from_le_bytes(fundraiser_state.pledged_amount), 0);
+        assert_eq!(
+            u64::from_le_bytes(fundraiser_state.current_amount),
+            100_000_000
+        );
+
+        Ok(())
+    }
+
+    // two pledges reach the goal, one is revoked before settle - the raise still succeeds
+    // and the honoured pledge settles after the default drops the total below the goal
+    pub fn pledge_default_function(svm: &mut LiteSVM, state: &ReusableState) -> Result<(), Error> {
+        let ReusableState {
+            maker,
+            fundraiser,
+            mint,
+            vault,
+            system_program,
+            token_program,
+            config,
+            ..
+        } = state;
+
+        let mut pledgers = Vec::new();
+
+        for amount in [60_000_000u64, 50_000_000u64] {
+            let contributor = Keypair::new();
+
+            svm.airdrop(&contributor.pubkey(), 10 * LAMPORTS_PER_SOL)
+                .expect("Airdrop failed");
+
+            let contributor_ata = CreateAssociatedTokenAccount::new(svm, &contributor, mint)
+                .owner(&contributor.pubkey())
+                .send()
+                .unwrap();
+
+            MintTo::new(svm, maker, mint, &contributor_ata, 1_000_000_000)
+                .send()
+                .unwrap();
+
+            let contributor_pda = Pubkey::find_program_address(
+                &[
+                    b"contributor".as_ref(),
+                    fundraiser.0.as_ref(),
+                    contributor.pubkey().as_ref(),
+                ],
+                &program_id(),
+            );
+
+            let pledge_ix = Instruction {
+                program_id: program_id(),
+                accounts: vec![
+                    AccountMeta::new(contributor.pubkey(), true),
+                    AccountMeta::new(fundraiser.0, false),
+                    AccountMeta::new(contributor_pda.0, false),
+                    AccountMeta::new(contributor_ata, false),
+                    AccountMeta::new_readonly(*system_program, false),
+                    AccountMeta::new_readonly(*token_program, false),
+                    AccountMeta::new_readonly(*config, false),
+                ],
+                data: [
+                    vec![FundraiserInstruction::Pledge as u8],
+                    amount.to_le_bytes().to_vec(),
+                ]
+                .concat(),
+            };
+
+            let message = Message::new(&[pledge_ix], Some(&contributor.pubkey()));
+            let recent_blockhash = svm.latest_blockhash();
+
+            let transaction = Transaction::new(&[&contributor], message, recent_blockhash);
+
+            svm.send_transaction(transaction).unwrap();
+
+            pledgers.push((contributor, contributor_ata, contributor_pda.0));
+        }
+
+        // the second pledger revokes the delegation before the deadline
+        let (defaulter, defaulter_ata, _) = &pledgers[1];
+
+        let revoke_ix =
+            spl_token::instruction::revoke(token_program, defaulter_ata, &defaulter.pubkey(), &[])
+                .unwrap();
+
+        let message = Message::new(&[revoke_ix], Some(&defaulter.pubkey()));
+        let recent_blockhash = svm.latest_blockhash();
+
+        let transaction = Transaction::new(&[defaulter], message, recent_blockhash);
+
+        svm.send_transaction(transaction).unwrap();
+
+        warp_forward(svm, 4 * 24 * 60 * 60);
+
+        let settler = Keypair::new();
+        svm.airdrop(&settler.pubkey(), LAMPORTS_PER_SOL)
+            .expect("Airdrop failed");
+
+        // settle the default first so the total drops below the goal before the honoured pledge
+        for (contributor, contributor_ata, contributor_pda) in pledgers.iter().rev() {
+            let settle_ix = Instruction {
+                program_id: program_id(),
+                accounts: vec![
+                    AccountMeta::new(fundraiser.0, false),
+                    AccountMeta::new_readonly(contributor.pubkey(), false),
+                    AccountMeta::new(*contributor_pda, false),
+                    AccountMeta::new(*contributor_ata, false),
+                    AccountMeta::new(*vault, false),
+                    AccountMeta::new_readonly(*token_program, false),
+                ],
+                data: vec![FundraiserInstruction::Settle as u8],
+            };
+
+            let message = Message::new(&[settle_ix], Some(&settler.pubkey()));
+            let recent_blockhash = svm.latest_blockhash();
+
+            let transaction = Transaction::new(&[&settler], message, recent_blockhash);
+
+            let tx = svm.send_transaction(transaction).unwrap();
+
+            println!("\nSettle transaction sucessfull");
+            println!("CUs Consumed: {}", tx.compute_units_consumed);
+        }
+
+        assert_eq!(token_balance(svm, &pledgers[0].1), 940_000_000);
+        assert_eq!(token_balance(svm, &pledgers[1].1), 1_000_000_000);
+        assert_eq!(token_balance(svm, vault), 60_000_000);
+
+        let fundraiser_account = svm.get_account(&fundraiser.0).unwrap();
+        let fundraiser_state =
+            bytemuck::from_bytes::<Fundraiser>(&fundraiser_account.data[..Fundraiser::LEN]);
+        assert_eq!(fundraiser_state.succeeded, [1]);
+        assert_eq!(u64::from_le_bytes(fundraiser_state.pledged_amount), 0);
+        assert_eq!(
+            u64::from_le_bytes(fundraiser_state.current_amount),
+            60_000_000
+        );
+
+        Ok(())
+    }
+
+    // pledges move no tokens before settle - the fundraiser can neither be matched nor join a round
+    pub fn pledge_fundraiser_unmatched_function(
+        svm: &mut LiteSVM,
+        state: &ReusableState,
+    ) -> Result<(), Error> {
+        let ReusableState {
+            maker,
+            maker_ata,
+            fundraiser,
+            mint,
+            system_program,
+            token_program,
+            ata_program,
+            ..
+        } = state;
+
+        MintTo::new(svm, maker, mint, maker_ata, 1_000_000_000)
+            .send()
+            .unwrap();
+
+        let matching =
+            Pubkey::find_program_address(&[b"matching", fundraiser.0.as_ref()], &program_id()).0;
+        let matching_vault =
+            spl_associated_token_account::get_associated_token_address(&matching, mint);
+
+        let matching_data = MatchingData {
+            ratio_bps: 10_000u16.to_le_bytes(),
+            cap: 150_000_000u64.to_le_bytes(),
+        };
+
+        let fund_matching_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new(*mint, false),
+                AccountMeta::new(fundraiser.0, false),
+                AccountMeta::new(matching, false),
+                AccountMeta::new(*maker_ata, false),
+                AccountMeta::new(matching_vault, false),
+                AccountMeta::new(*system_program, false),
+                AccountMeta::new(*token_program, false),
+                AccountMeta::new(*ata_program, false),
+                AccountMeta::new_readonly(maker.pubkey(), true),
+            ],
+            data: [
+                (FundraiserInstruction::FundMatching as u8)
+                    .to_le_bytes()
+                    .to_vec(),
+                matching_data.to_bytes().to_vec(),
+            ]
+            .concat(),
+        };
+
+        let message = Message::new(&[fund_matching_ix], Some(&maker.pubkey()));
+        let transaction = Transaction::new(&[maker], message, svm.latest_blockhash());
+        assert!(svm.send_transaction(transaction).is_err());
+        assert!(svm.get_account(&matching).is_none());
+
+        let round =
+            Pubkey::find_program_address(&[b"round", maker.pubkey().as_ref()], &program_id()).0;
+        let pool_vault = spl_associated_token_account::get_associated_token_address(&round, mint);
+
+        let round_data = RoundData {
+            pool_amount: 1_000_000_000u64.to_le_bytes(),
+            end_time: (current_unix_timestamp(svm) + 24 * 60 * 60).to_le_bytes(),
+        };
+
+        let init_round_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new(*mint, false),
+                AccountMeta::new(round, false),
+                AccountMeta::new(*maker_ata, false),
+                AccountMeta::new(pool_vault, false),
+                AccountMeta::new(*system_program, false),
+                AccountMeta::new(*token_program, false),
+                AccountMeta::new(*ata_program, false),
+            ],
+            data: [
+                (FundraiserInstruction::InitRound as u8)
+                    .to_le_bytes()
+                    .to_vec(),
+                round_data.to_bytes().to_vec(),
+            ]
+            .concat(),
+        };
+
+        let message = Message::new(&[init_round_ix], Some(&maker.pubkey()));
+        let transaction = Transaction::new(&[maker], message, svm.latest_blockhash());
+        svm.send_transaction(transaction).unwrap();
+
+        let join_round_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(maker.pubkey(), true),
+                AccountMeta::new(fundraiser.0, false),
+                AccountMeta::new(round, false),
+            ],
+            data: (FundraiserInstruction::JoinRound as u8)
+                .to_le_bytes()
+                .to_vec(),
+        };
+
+        let message = Message::new(&[join_round_ix], Some(&maker.pubkey()));
+        let transaction = Transaction::new(&[maker], message, svm.latest_blockhash());
+        assert!(svm.send_transaction(transaction).is_err());
+
+        let fundraiser_account = svm.get_account(&fundraiser.0).unwrap();
+        let fundraiser_state =
+            bytemuck::from_bytes::<Fundraiser>(&fundraiser_account.data[..Fundraiser::LEN]);
+        assert_eq!(fundraiser_state.has_matching, [0]);
+        assert!(!fundraiser_state.has_round());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,332 @@
+#[cfg(test)]
+pub mod receipt {
+    use core::fmt::Error;
+
+    use bytemuck::Zeroable;
+
+    use crate::instructions::{ContributeData, FundraiserInstruction, InitData};
+    use crate::state::{Fundraiser, GOAL_MODE_TOKEN};
+    use crate::tests::tests::{program_id, token_balance, ReusableState};
+    use crate::tests::voting::voting::VOTE_DURATION;
+    use litesvm::LiteSVM;
+    use litesvm_token::{CreateAssociatedTokenAccount, MintTo};
+    use solana_instruction::{AccountMeta, Instruction};
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_native_token::LAMPORTS_PER_SOL;
+    use solana_pubkey::Pubkey;
+    use solana_sdk_ids::sysvar::rent;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    // spl token account state sits at offset 108, 2 once frozen
+    fn is_frozen(svm: &LiteSVM, token_account: &Pubkey) -> bool {
+        svm.get_account(token_account).unwrap().data[108] == 2
+    }
+
+    // 100 usdc milestone raise minting fungible receipts, contributors can pull out early for a
+    // 10% penalty or claim back what is left once aborted - returns the receipt mint
+    pub fn create_receipt_fundraiser_function(
+        svm: &mut LiteSVM,
+        state: &ReusableState,
+    ) -> Result<Pubkey, Error> {
+        let ReusableState {
+            maker,
+            fundraiser,
+            mint,
+            vault,
+            system_program,
+            token_program,
+            ata_program,
+            config,
+            ..
+        } = state;
+
+        let receipt_mint =
+            Pubkey::find_program_address(&[b"receipt", fundraiser.0.as_ref()], &program_id()).0;
+
+        let mut milestones_bps = [[0u8; 2]; 8];
+        milestones_bps[0] = 5_000u16.to_le_bytes();
+        milestones_bps[1] = 5_000u16.to_le_bytes();
+
+        let initialize_data = InitData {
+            amount_to_raise: 100_000_000u64.to_le_bytes(),
+            duration: 3u8.to_le_bytes(),
+            goal_mode: [GOAL_MODE_TOKEN],
+            quorum_bps: 5_000u16.to_le_bytes(),
+            threshold_bps: 6_000u16.to_le_bytes(),
+            vote_duration: (VOTE_DURATION as u64).to_le_bytes(),
+            milestones_count: [2],
+            milestones_bps,
+            receipt: [1],
+            early_withdraw: [1],
+            early_withdraw_penalty_bps: 1_000u16.to_le_bytes(),
+            ..Zeroable::zeroed()
+        };
+
+        let make_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new(*mint, false),
+                AccountMeta::new(fundraiser.0, false),
+                AccountMeta::new(*vault, false),
+                AccountMeta::new(*system_program, false),
+                AccountMeta::new(*token_program, false),
+                AccountMeta::new(*ata_program, false),
+                AccountMeta::new(rent::ID, false),
+                AccountMeta::new_readonly(*config, false),
+                AccountMeta::new(receipt_mint, false),
+            ],
+            data: [
+                (FundraiserInstruction::Initialize as u8)
+                    .to_le_bytes()
+                    .to_vec(),
+                initialize_data.to_bytes().to_vec(),
+            ]
+            .concat(),
+        };
+
+        let message = Message::new(&[make_ix], Some(&maker.pubkey()));
+        let recent_blockhash = svm.latest_blockhash();
+
+        let transaction = Transaction::new(&[&maker], message, recent_blockhash);
+
+        let tx = svm.send_transaction(transaction).unwrap();
+
+        println!("\nReceipt initialize transaction sucessfull");
+        println!("CUs Consumed: {}", tx.compute_units_consumed);
+
+        let fundraiser_account = svm.get_account(&fundraiser.0).unwrap();
+        let fundraiser_state =
+            bytemuck::from_bytes::<Fundraiser>(&fundraiser_account.data[..Fundraiser::LEN]);
+        assert_eq!(fundraiser_state.receipt_mint, receipt_mint.to_bytes());
+
+        Ok(receipt_mint)
+    }
+
+    // a 100 usdc deposit mints 100 usdc worth of receipts, frozen to the contributor - returns
+    // the contributor
+    pub fn donate_for_receipt_function(
+        svm: &mut LiteSVM,
+        state: &ReusableState,
+        receipt_mint: &Pubkey,
+    ) -> Result<Keypair, Error> {
+        let ReusableState {
+            maker,
+            fundraiser,
+            mint,
+            vault,
+            system_program,
+            token_program,
+            ata_program,
+            config,
+            ..
+        } = state;
+
+        let contributor = Keypair::new();
+
+        svm.airdrop(&contributor.pubkey(), 10 * LAMPORTS_PER_SOL)
+            .expect("Airdrop failed");
+
+        let contributor_ata = CreateAssociatedTokenAccount::new(svm, &contributor, mint)
+            .owner(&contributor.pubkey())
+            .send()
+            .unwrap();
+
+        MintTo::new(svm, maker, mint, &contributor_ata, 1_000_000_000)
+            .send()
+            .unwrap();
+
+        let contributor_pda = Pubkey::find_program_address(
+            &[
+                b"contributor".as_ref(),
+                fundraiser.0.as_ref(),
+                contributor.pubkey().as_ref(),
+            ],
+            &program_id(),
+        );
+
+        let receipt_account = spl_associated_token_account::get_associated_token_address(
+            &contributor.pubkey(),
+            receipt_mint,
+        );
+
+        let contribute_data = ContributeData {
+            amount: 100_000_000u64.to_le_bytes(),
+            min_out: 0u64.to_le_bytes(),
+            swap: [0],
+            swap_accounts: [0],
+        };
+
+        let donate_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(contributor.pubkey(), true),
+                AccountMeta::new(*mint, false),
+                AccountMeta::new(fundraiser.0, false),
+                AccountMeta::new(contributor_pda.0, false),
+                AccountMeta::new(contributor_ata, false),
+                AccountMeta::new(*vault, false),
+                AccountMeta::new(*system_program, false),
+                AccountMeta::new(*token_program, false),
+                AccountMeta::new(*ata_program, false),
+                AccountMeta::new(rent::ID, false),
+                AccountMeta::new_readonly(*config, false),
+                AccountMeta::new(*receipt_mint, false),
+                AccountMeta::new(receipt_account, false),
+            ],
+            data: [
+                (FundraiserInstruction::Deposit as u8)
+                    .to_le_bytes()
+                    .to_vec(),
+                contribute_data.to_bytes().to_vec(),
+            ]
+            .concat(),
+        };
+
+        let message = Message::new(&[donate_ix], Some(&contributor.pubkey()));
+        let recent_blockhash = svm.latest_blockhash();
+
+        let transaction = Transaction::new(&[&contributor], message, recent_blockhash);
+
+        let tx = svm.send_transaction(transaction).unwrap();
+
+        println!("\nReceipt donate transaction sucessfull");
+        println!("CUs Consumed: {}", tx.compute_units_consumed);
+
+        assert_eq!(token_balance(svm, &receipt_account), 100_000_000);
+        assert!(is_frozen(svm, &receipt_account));
+
+        Ok(contributor)
+    }
+
+    // the early withdrawal refunds 90 usdc and burns every receipt of the position
+    pub fn withdraw_receipt_contribution_function(
+        svm: &mut LiteSVM,
+        state: &ReusableState,
+        receipt_mint: &Pubkey,
+        contributor: &Keypair,
+    ) -> Result<(), Error> {
+        let ReusableState {
+            fundraiser,
+            mint,
+            vault,
+            token_program,
+            config,
+            ..
+        } = state;
+
+        let contributor_pda = Pubkey::find_program_address(
+            &[
+                b"contributor".as_ref(),
+                fundraiser.0.as_ref(),
+                contributor.pubkey().as_ref(),
+            ],
+            &program_id(),
+        );
+        let contributor_ata =
+            spl_associated_token_account::get_associated_token_address(&contributor.pubkey(), mint);
+        let receipt_account = spl_associated_token_account::get_associated_token_address(
+            &contributor.pubkey(),
+            receipt_mint,
+        );
+
+        let withdraw_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(contributor.pubkey(), true),
+                AccountMeta::new(fundraiser.0, false),
+                AccountMeta::new(contributor_pda.0, false),
+                AccountMeta::new_readonly(*token_program, false),
+                AccountMeta::new_readonly(*config, false),
+                AccountMeta::new(*vault, false),
+                AccountMeta::new(contributor_ata, false),
+                AccountMeta::new(*receipt_mint, false),
+                AccountMeta::new(receipt_account, false),
+            ],
+            data: vec![FundraiserInstruction::WithdrawContribution as u8],
+        };
+
+        let message = Message::new(&[withdraw_ix], Some(&contributor.pubkey()));
+        let recent_blockhash = svm.latest_blockhash();
+
+        let transaction = Transaction::new(&[contributor], message, recent_blockhash);
+
+        let tx = svm.send_transaction(transaction).unwrap();
+
+        println!("\nReceipt withdraw contribution transaction sucessfull");
+        println!("CUs Consumed: {}", tx.compute_units_consumed);
+
+        assert_eq!(token_balance(svm, &contributor_ata), 990_000_000);
+        assert_eq!(token_balance(svm, &receipt_account), 0);
+        assert!(is_frozen(svm, &receipt_account));
+
+        Ok(())
+    }
+
+    // claiming the aborted raise remainder burns every receipt of the position
+    pub fn claim_receipt_remainder_function(
+        svm: &mut LiteSVM,
+        state: &ReusableState,
+        receipt_mint: &Pubkey,
+        contributor: &Keypair,
+        proposal: &Pubkey,
+    ) -> Result<(), Error> {
+        let ReusableState {
+            fundraiser,
+            mint,
+            vault,
+            token_program,
+            config,
+            ..
+        } = state;
+
+        let contributor_pda = Pubkey::find_program_address(
+            &[
+                b"contributor".as_ref(),
+                fundraiser.0.as_ref(),
+                contributor.pubkey().as_ref(),
+            ],
+            &program_id(),
+        );
+        let contributor_ata =
+            spl_associated_token_account::get_associated_token_address(&contributor.pubkey(), mint);
+        let receipt_account = spl_associated_token_account::get_associated_token_address(
+            &contributor.pubkey(),
+            receipt_mint,
+        );
+
+        let claim_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(contributor.pubkey(), true),
+                AccountMeta::new(fundraiser.0, false),
+                AccountMeta::new(contributor_pda.0, false),
+                AccountMeta::new_readonly(*proposal, false),
+                AccountMeta::new_readonly(*token_program, false),
+                AccountMeta::new_readonly(*config, false),
+                AccountMeta::new(*vault, false),
+                AccountMeta::new(contributor_ata, false),
+                AccountMeta::new(*receipt_mint, false),
+                AccountMeta::new(receipt_account, false),
+            ],
+            data: vec![FundraiserInstruction::ClaimRemainder as u8],
+        };
+
+        let message = Message::new(&[claim_ix], Some(&contributor.pubkey()));
+        let recent_blockhash = svm.latest_blockhash();
+
+        let transaction = Transaction::new(&[contributor], message, recent_blockhash);
+
+        let tx = svm.send_transaction(transaction).unwrap();
+
+        println!("\nReceipt claim remainder transaction sucessfull");
+        println!("CUs Consumed: {}", tx.compute_units_consumed);
+
+        assert_eq!(token_balance(svm, &contributor_ata), 1_000_000_000);
+        assert_eq!(token_balance(svm, &receipt_account), 0);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,483 @@
+#[cfg(test)]
+pub mod round {
+    use core::fmt::Error;
+
+    use bytemuck::Zeroable;
+
+    use crate::instructions::{ContributeData, FundraiserInstruction, InitData, RoundData};
+    use crate::state::{Fundraiser, Round, GOAL_MODE_TOKEN};
+    use crate::tests::tests::{
+        current_unix_timestamp, program_id, token_balance, warp_forward, ReusableState,
+    };
+    use litesvm::LiteSVM;
+    use litesvm_token::{CreateAssociatedTokenAccount, MintTo};
+    use solana_instruction::{AccountMeta, Instruction};
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_native_token::LAMPORTS_PER_SOL;
+    use solana_pubkey::Pubkey;
+    use solana_sdk_ids::sysvar::rent;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    // maker creates a round with a 1k usdc pool and enrolls their fundraiser, returns the round
+    pub fn create_and_join_round_function(
+        svm: &mut LiteSVM,
+        state: &ReusableState,
+    ) -> Result<Pubkey, Error> {
+        let ReusableState {
+            maker,
+            maker_ata,
+            fundraiser,
+            mint,
+            system_program,
+            token_program,
+            ata_program,
+            ..
+        } = state;
+
+        MintTo::new(svm, maker, mint, maker_ata, 1_000_000_000)
+            .send()
+            .unwrap();
+
+        let round =
+            Pubkey::find_program_address(&[b"round", maker.pubkey().as_ref()], &program_id()).0;
+        let pool_vault = spl_associated_token_account::get_associated_token_address(&round, mint);
+
+        let now = current_unix_timestamp(svm);
+
+        let round_data = RoundData {
+            pool_amount: 1_000_000_000u64.to_le_bytes(),
+            end_time: (now + 24 * 60 * 60).to_le_bytes(),
+        };
+
+        let init_round_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new(*mint, false),
+                AccountMeta::new(round, false),
+                AccountMeta::new(*maker_ata, false),
+                AccountMeta::new(pool_vault, false),
+                AccountMeta::new(*system_program, false),
+                AccountMeta::new(*token_program, false),
+                AccountMeta::new(*ata_program, false),
+            ],
+            data: [
+                (FundraiserInstruction::InitRound as u8)
+                    .to_le_bytes()
+                    .to_vec(),
+                round_data.to_bytes().to_vec(),
+            ]
+            .concat(),
+        };
+
+        let join_round_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(maker.pubkey(), true),
+                AccountMeta::new(fundraiser.0, false),
+                AccountMeta::new(round, false),
+            ],
+            data: (FundraiserInstruction::JoinRound as u8)
+                .to_le_bytes()
+                .to_vec(),
+        };
+
+        let message = Message::new(&[init_round_ix, join_round_ix], Some(&maker.pubkey()));
+        let recent_blockhash = svm.latest_blockhash();
+
+        let transaction = Transaction::new(&[&maker], message, recent_blockhash);
+
+        let tx = svm.send_transaction(transaction).unwrap();
+
+        println!("\nInit and join round transaction sucessfull");
+        println!("CUs Consumed: {}", tx.compute_units_consumed);
+
+        let round_account = svm.get_account(&round).unwrap();
+        let round_state = bytemuck::from_bytes::<Round>(&round_account.data);
+        assert_eq!(round_state.projects_count, [1]);
+        assert_eq!(round_state.projects[0].fundraiser, fundraiser.0.to_bytes());
+
+        Ok(round)
+    }
+
+    pub fn donate_in_round_function(
+        svm: &mut LiteSVM,
+        state: &ReusableState,
+        round: &Pubkey,
+    ) -> Result<(), Error> {
+        let ReusableState {
+            maker,
+            fundraiser,
+            mint,
+            vault,
+            system_program,
+            token_program,
+            ata_program,
+            config,
+            ..
+        } = state;
+
+        let amount_to_donate: u64 = 100_000_000; // 100 usdc - 6 decimals
+
+        let contributor = Keypair::new();
+
+        svm.airdrop(&contributor.pubkey(), 10 * LAMPORTS_PER_SOL)
+            .expect("Airdrop failed");
+
+        let contributor_ata = CreateAssociatedTokenAccount::new(svm, &contributor, mint)
+            .owner(&contributor.pubkey())
+            .send()
+            .unwrap();
+
+        MintTo::new(svm, maker, mint, &contributor_ata, 1_000_000_000)
+            .send()
+            .unwrap();
+
+        let contributor_pda = Pubkey::find_program_address(
+            &[
+                b"contributor".as_ref(),
+                fundraiser.0.as_ref(),
+                contributor.pubkey().as_ref(),
+            ],
+            &program_id(),
+        );
+
+        let contribute_data = ContributeData {
+            amount: amount_to_donate.to_le_bytes(),
+            min_out: 0u64.to_le_bytes(),
+            swap: [0],
+            swap_accounts: [0],
+        };
+
+        let donate_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(contributor.pubkey(), true),
+                AccountMeta::new(*mint, false),
+                AccountMeta::new(fundraiser.0, false),
+                AccountMeta::new(contributor_pda.0, false),
+                AccountMeta::new(contributor_ata, false),
+                AccountMeta::new(*vault, false),
+                AccountMeta::new(*system_program, false),
+                AccountMeta::new(*token_program, false),
+                AccountMeta::new(*ata_program, false),
+                AccountMeta::new(rent::ID, false),
+                AccountMeta::new_readonly(*config, false),
+                AccountMeta::new(*round, false),
+            ],
+            data: [
+                (FundraiserInstruction::Deposit as u8)
+                    .to_le_bytes()
+                    .to_vec(),
+                contribute_data.to_bytes().to_vec(),
+            ]
+            .concat(),
+        };
+
+        let message = Message::new(&[donate_ix], Some(&contributor.pubkey()));
+        let recent_blockhash = svm.latest_blockhash();
+
+        let transaction = Transaction::new(&[&contributor], message, recent_blockhash);
+
+        let tx = svm.send_transaction(transaction).unwrap();
+
+        println!("\nDonate in round transaction sucessfull");
+        println!("CUs Consumed: {}", tx.compute_units_consumed);
+
+        // sqrt(100_000_000)
+        let round_account = svm.get_account(round).unwrap();
+        let round_state = bytemuck::from_bytes::<Round>(&round_account.data);
+        assert_eq!(u64::from_le_bytes(round_state.projects[0].sum_sqrt), 10_000);
+
+        Ok(())
+    }
+
+    // a second project joins the round with two 100 usdc backers against the single 100 usdc
+    // backer of the first - weights are (sum of sqrt)^2 so the pool splits 1:4, not 1:2
+    pub fn distribute_matching_function(
+        svm: &mut LiteSVM,
+        state: &ReusableState,
+        round: &Pubkey,
+    ) -> Result<(), Error> {
+        let ReusableState {
+            maker,
+            fundraiser,
+            mint,
+            vault,
+            system_program,
+            token_program,
+            ata_program,
+            config,
+            ..
+        } = state;
+
+        let second_maker = Keypair::new();
+
+        svm.airdrop(&second_maker.pubkey(), 10 * LAMPORTS_PER_SOL)
+            .expect("Airdrop failed");
+
+        let second_fundraiser = Pubkey::find_program_address(
+            &[b"fundraiser", second_maker.pubkey().as_ref()],
+            &program_id(),
+        )
+        .0;
+        let second_vault =
+            spl_associated_token_account::get_associated_token_address(&second_fundraiser, mint);
+
+        let initialize_data = InitData {
+            amount_to_raise: 10_000_000_000u64.to_le_bytes(),
+            duration: 3u8.to_le_bytes(),
+            goal_mode: [GOAL_MODE_TOKEN],
+            max_amount_sendable: 4000_000_000u64.to_le_bytes(),
+            min_amount_sendable: 10_000_000u64.to_le_bytes(),
+            ..Zeroable::zeroed()
+        };
+
+        let make_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(second_maker.pubkey(), true),
+                AccountMeta::new(*mint, false),
+                AccountMeta::new(second_fundraiser, false),
+                AccountMeta::new(second_vault, false),
+                AccountMeta::new(*system_program, false),
+                AccountMeta::new(*token_program, false),
+                AccountMeta::new(*ata_program, false),
+                AccountMeta::new(rent::ID, false),
+                AccountMeta::new_readonly(*config, false),
+            ],
+            data: [
+                (FundraiserInstruction::Initialize as u8)
+                    .to_le_bytes()
+                    .to_vec(),
+                initialize_data.to_bytes().to_vec(),
+            ]
+            .concat(),
+        };
+
+        // the round creator accepts the second project
+        let join_round_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(second_maker.pubkey(), true),
+                AccountMeta::new_readonly(maker.pubkey(), true),
+                AccountMeta::new(second_fundraiser, false),
+                AccountMeta::new(*round, false),
+            ],
+            data: (FundraiserInstruction::JoinRound as u8)
+                .to_le_bytes()
+                .to_vec(),
+        };
+
+        let message = Message::new(&[make_ix, join_round_ix], Some(&second_maker.pubkey()));
+        let recent_blockhash = svm.latest_blockhash();
+
+        let transaction = Transaction::new(&[&second_maker, maker], message, recent_blockhash);
+
+        svm.send_transaction(transaction).unwrap();
+
+        for _ in 0..2 {
+            let contributor = Keypair::new();
+
+            svm.airdrop(&contributor.pubkey(), 10 * LAMPORTS_PER_SOL)
+                .expect("Airdrop failed");
+
+            let contributor_ata = CreateAssociatedTokenAccount::new(svm, &contributor, mint)
+                .owner(&contributor.pubkey())
+                .send()
+                .unwrap();
+
+            MintTo::new(svm, maker, mint, &contributor_ata, 1_000_000_000)
+                .send()
+                .unwrap();
+
+            let contributor_pda = Pubkey::find_program_address(
+                &[
+                    b"contributor".as_ref(),
+                    second_fundraiser.as_ref(),
+                    contributor.pubkey().as_ref(),
+                ],
+                &program_id(),
+            );
+
+            let contribute_data = ContributeData {
+                amount: 100_000_000u64.to_le_bytes(),
+                min_out: 0u64.to_le_bytes(),
+                swap: [0],
+                swap_accounts: [0],
+            };
+
+            let donate_ix = Instruction {
+                program_id: program_id(),
+                accounts: vec![
+                    AccountMeta::new(contributor.pubkey(), true),
+                    AccountMeta::new(*mint, false),
+                    AccountMeta::new(second_fundraiser, false),
+                    AccountMeta::new(contributor_pda.0, false),
+                    AccountMeta::new(contributor_ata, false),
+                    AccountMeta::new(second_vault, false),
+                    AccountMeta::new(*system_program, false),
+                    AccountMeta::new(*token_program, false),
+                    AccountMeta::new(*ata_program, false),
+                    AccountMeta::new(rent::ID, false),
+                    AccountMeta::new_readonly(*config, false),
+                    AccountMeta::new(*round, false),
+                ],
+                data: [
+                    (FundraiserInstruction::Deposit as u8)
+                        .to_le_bytes()
+                        .to_vec(),
+                    contribute_data.to_bytes().to_vec(),
+                ]
+                .concat(),
+            };
+
+            let message = Message::new(&[donate_ix], Some(&contributor.pubkey()));
+            let recent_blockhash = svm.latest_blockhash();
+
+            let transaction = Transaction::new(&[&contributor], message, recent_blockhash);
+
+            svm.send_transaction(transaction).unwrap();
+        }
+
+        // 2 * sqrt(100_000_000)
+        let round_account = svm.get_account(round).unwrap();
+        let round_state = bytemuck::from_bytes::<Round>(&round_account.data);
+        assert_eq!(u64::from_le_bytes(round_state.projects[1].sum_sqrt), 20_000);
+
+        warp_forward(svm, 24 * 60 * 60);
+
+        let pool_vault = spl_associated_token_account::get_associated_token_address(round, mint);
+
+        // permissionless - anyone can pay for the distribution
+        let distributor = Keypair::new();
+        svm.airdrop(&distributor.pubkey(), LAMPORTS_PER_SOL)
+            .expect("Airdrop failed");
+
+        let distribute_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(*round, false),
+                AccountMeta::new(pool_vault, false),
+                AccountMeta::new_readonly(*token_program, false),
+                AccountMeta::new(fundraiser.0, false),
+                AccountMeta::new(*vault, false),
+                AccountMeta::new(second_fundraiser, false),
+                AccountMeta::new(second_vault, false),
+            ],
+            data: (FundraiserInstruction::DistributeMatching as u8)
+                .to_le_bytes()
+                .to_vec(),
+        };
+
+        let message = Message::new(&[distribute_ix], Some(&distributor.pubkey()));
+        let recent_blockhash = svm.latest_blockhash();
+
+        let transaction = Transaction::new(&[&distributor], message, recent_blockhash);
+
+        let tx = svm.send_transaction(transaction).unwrap();
+
+        println!("\nDistribute matching transaction sucessfull");
+        println!("CUs Consumed: {}", tx.compute_units_consumed);
+
+        // 1k usdc pool: 10_000^2 vs 20_000^2
+        assert_eq!(token_balance(svm, &pool_vault), 0);
+        assert_eq!(token_balance(svm, vault), 100_000_000 + 200_000_000);
+        assert_eq!(token_balance(svm, &second_vault), 200_000_000 + 800_000_000);
+
+        let fundraiser_account = svm.get_account(&second_fundraiser).unwrap();
+        let fundraiser_state =
+            bytemuck::from_bytes::<Fundraiser>(&fundraiser_account.data[..Fundraiser::LEN]);
+        assert_eq!(
+            u64::from_le_bytes(fundraiser_state.matched_amount),
+            800_000_000
+        );
+        assert_eq!(
+            u64::from_le_bytes(fundraiser_state.current_amount),
+            1_000_000_000
+        );
+
+        let round_account = svm.get_account(round).unwrap();
+        let round_state = bytemuck::from_bytes::<Round>(&round_account.data);
+        assert_eq!(round_state.distributed, [1]);
+
+        Ok(())
+    }
+
+    // the raise met its goal and ended, but its round share is still in the pool - claim waits
+    // for the distribution, then pays the share out with the deposits
+    pub fn claim_after_distribution_function(
+        svm: &mut LiteSVM,
+        state: &ReusableState,
+        round: &Pubkey,
+        sponsor_ata: &Pubkey,
+    ) -> Result<(), Error> {
+        let ReusableState {
+            maker,
+            maker_ata,
+            fundraiser,
+            mint,
+            vault,
+            token_program,
+            ..
+        } = state;
+
+        warp_forward(svm, 4 * 24 * 60 * 60);
+
+        let claim_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new(fundraiser.0, false),
+                AccountMeta::new_readonly(*token_program, false),
+                AccountMeta::new(*vault, false),
+                AccountMeta::new(*maker_ata, false),
+                AccountMeta::new(*sponsor_ata, false),
+            ],
+            data: vec![FundraiserInstruction::Claim as u8],
+        };
+
+        let message = Message::new(&[claim_ix.clone()], Some(&maker.pubkey()));
+        let recent_blockhash = svm.latest_blockhash();
+
+        let transaction = Transaction::new(&[maker], message, recent_blockhash);
+
+        assert!(svm.send_transaction(transaction).is_err());
+
+        let pool_vault = spl_associated_token_account::get_associated_token_address(round, mint);
+
+        let distribute_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(*round, false),
+                AccountMeta::new(pool_vault, false),
+                AccountMeta::new_readonly(*token_program, false),
+                AccountMeta::new(fundraiser.0, false),
+                AccountMeta::new(*vault, false),
+            ],
+            data: (FundraiserInstruction::DistributeMatching as u8)
+                .to_le_bytes()
+                .to_vec(),
+        };
+
+        let message = Message::new(&[distribute_ix, claim_ix], Some(&maker.pubkey()));
+        let recent_blockhash = svm.latest_blockhash();
+
+        let transaction = Transaction::new(&[maker], message, recent_blockhash);
+
+        let tx = svm.send_transaction(transaction).unwrap();
+
+        println!("\nDistribute and claim transaction sucessfull");
+        println!("CUs Consumed: {}", tx.compute_units_consumed);
+
+        // 100 donated + the whole 1k pool, split 70 / 30
+        assert_eq!(token_balance(svm, vault), 0);
+        assert_eq!(token_balance(svm, maker_ata), 770_000_000);
+        assert_eq!(token_balance(svm, sponsor_ata), 330_000_000);
+
+        Ok(())
+    }
+}
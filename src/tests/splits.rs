@@ -0,0 +1,140 @@
+#[cfg(test)]
+pub mod splits {
+    use core::fmt::Error;
+
+    use bytemuck::Zeroable;
+
+    use crate::instructions::{FundraiserInstruction, InitData};
+    use crate::state::{PayoutSplit, GOAL_MODE_TOKEN, MAX_PAYOUT_SPLITS};
+    use crate::tests::tests::{program_id, token_balance, ReusableState};
+    use litesvm::LiteSVM;
+    use litesvm_token::CreateAssociatedTokenAccount;
+    use solana_instruction::{AccountMeta, Instruction};
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_pubkey::Pubkey;
+    use solana_sdk_ids::sysvar::rent;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    // 100 usdc goal split 70 / 30 between the maker and a fiscal sponsor, returns the sponsor ata
+    pub fn create_split_fundraiser_function(
+        svm: &mut LiteSVM,
+        state: &ReusableState,
+    ) -> Result<Pubkey, Error> {
+        let ReusableState {
+            maker,
+            fundraiser,
+            mint,
+            vault,
+            system_program,
+            token_program,
+            ata_program,
+            config,
+            ..
+        } = state;
+
+        let sponsor = Keypair::new();
+        let sponsor_ata = CreateAssociatedTokenAccount::new(svm, maker, mint)
+            .owner(&sponsor.pubkey())
+            .send()
+            .unwrap();
+
+        let mut splits = [PayoutSplit::zeroed(); MAX_PAYOUT_SPLITS];
+        splits[0] = PayoutSplit {
+            recipient: maker.pubkey().to_bytes(),
+            bps: 7_000u16.to_le_bytes(),
+        };
+        splits[1] = PayoutSplit {
+            recipient: sponsor.pubkey().to_bytes(),
+            bps: 3_000u16.to_le_bytes(),
+        };
+
+        let initialize_data = InitData {
+            amount_to_raise: 100_000_000u64.to_le_bytes(),
+            duration: 3u8.to_le_bytes(),
+            goal_mode: [GOAL_MODE_TOKEN],
+            splits_count: [2],
+            splits,
+            ..Zeroable::zeroed()
+        };
+
+        let make_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new(*mint, false),
+                AccountMeta::new(fundraiser.0, false),
+                AccountMeta::new(*vault, false),
+                AccountMeta::new(*system_program, false),
+                AccountMeta::new(*token_program, false),
+                AccountMeta::new(*ata_program, false),
+                AccountMeta::new(rent::ID, false),
+                AccountMeta::new_readonly(*config, false),
+            ],
+            data: [
+                (FundraiserInstruction::Initialize as u8)
+                    .to_le_bytes()
+                    .to_vec(),
+                initialize_data.to_bytes().to_vec(),
+            ]
+            .concat(),
+        };
+
+        let message = Message::new(&[make_ix], Some(&maker.pubkey()));
+        let recent_blockhash = svm.latest_blockhash();
+
+        let transaction = Transaction::new(&[&maker], message, recent_blockhash);
+
+        let tx = svm.send_transaction(transaction).unwrap();
+
+        println!("\nSplit initialize transaction sucessfull");
+        println!("CUs Consumed: {}", tx.compute_units_consumed);
+
+        Ok(sponsor_ata)
+    }
+
+    pub fn claim_split_function(
+        svm: &mut LiteSVM,
+        state: &ReusableState,
+        sponsor_ata: &Pubkey,
+    ) -> Result<(), Error> {
+        let ReusableState {
+            maker,
+            maker_ata,
+            fundraiser,
+            vault,
+            token_program,
+            ..
+        } = state;
+
+        let claim_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new(fundraiser.0, false),
+                AccountMeta::new_readonly(*token_program, false),
+                AccountMeta::new(*vault, false),
+                AccountMeta::new(*maker_ata, false),
+                AccountMeta::new(*sponsor_ata, false),
+            ],
+            data: vec![FundraiserInstruction::Claim as u8],
+        };
+
+        let message = Message::new(&[claim_ix], Some(&maker.pubkey()));
+        let recent_blockhash = svm.latest_blockhash();
+
+        let transaction = Transaction::new(&[&maker], message, recent_blockhash);
+
+        let tx = svm.send_transaction(transaction).unwrap();
+
+        println!("\nClaim split transaction sucessfull");
+        println!("CUs Consumed: {}", tx.compute_units_consumed);
+
+        assert_eq!(token_balance(svm, maker_ata), 70_000_000);
+        assert_eq!(token_balance(svm, sponsor_ata), 30_000_000);
+        assert_eq!(token_balance(svm, vault), 0);
+
+        Ok(())
+    }
+}
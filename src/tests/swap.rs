@@ -0,0 +1,165 @@
+#[cfg(test)]
+pub mod swap {
+    use core::fmt::Error;
+
+    use crate::instructions::{ContributeData, FundraiserInstruction};
+    use crate::state::Contributor;
+    use crate::tests::tests::{program_id, token_balance, ReusableState};
+    use litesvm::LiteSVM;
+    use litesvm_token::{CreateAssociatedTokenAccount, CreateMint, MintTo};
+    use solana_instruction::{AccountMeta, Instruction};
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_native_token::LAMPORTS_PER_SOL;
+    use solana_pubkey::Pubkey;
+    use solana_sdk_ids::sysvar::rent;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    const MOCK_SWAP_PROGRAM_ID: &str = "64gYuQi574h1evdHjdR1a5fAxnv88iEvpA4yqvrmHDe1";
+
+    // the contributor swaps 100 input tokens into the raised mint as part of the deposit
+    pub fn donate_with_swap_function(
+        svm: &mut LiteSVM,
+        state: &ReusableState,
+    ) -> Result<(), Error> {
+        let ReusableState {
+            maker,
+            fundraiser,
+            mint,
+            vault,
+            system_program,
+            token_program,
+            ata_program,
+            config,
+            ..
+        } = state;
+
+        let mock_swap_program = MOCK_SWAP_PROGRAM_ID.parse::<Pubkey>().unwrap();
+        svm.add_program(
+            mock_swap_program,
+            include_bytes!("../../target/deploy/mock_swap.so"),
+        )
+        .expect("Failed to add mock swap program");
+
+        // pool with 1k of each token
+        let pool_authority = Pubkey::find_program_address(&[b"pool"], &mock_swap_program).0;
+
+        let input_mint = CreateMint::new(svm, maker)
+            .decimals(6)
+            .authority(&maker.pubkey())
+            .send()
+            .unwrap();
+
+        let pool_source = CreateAssociatedTokenAccount::new(svm, maker, &input_mint)
+            .owner(&pool_authority)
+            .send()
+            .unwrap();
+        let pool_destination = CreateAssociatedTokenAccount::new(svm, maker, mint)
+            .owner(&pool_authority)
+            .send()
+            .unwrap();
+
+        MintTo::new(svm, maker, &input_mint, &pool_source, 1_000_000_000)
+            .send()
+            .unwrap();
+        MintTo::new(svm, maker, mint, &pool_destination, 1_000_000_000)
+            .send()
+            .unwrap();
+
+        // contributor only holds the input token
+        let contributor = Keypair::new();
+
+        svm.airdrop(&contributor.pubkey(), 10 * LAMPORTS_PER_SOL)
+            .expect("Airdrop failed");
+
+        let source_ata = CreateAssociatedTokenAccount::new(svm, &contributor, &input_mint)
+            .owner(&contributor.pubkey())
+            .send()
+            .unwrap();
+        let contributor_ata = CreateAssociatedTokenAccount::new(svm, &contributor, mint)
+            .owner(&contributor.pubkey())
+            .send()
+            .unwrap();
+
+        MintTo::new(svm, maker, &input_mint, &source_ata, 1_000_000_000)
+            .send()
+            .unwrap();
+
+        let contributor_pda = Pubkey::find_program_address(
+            &[
+                b"contributor".as_ref(),
+                fundraiser.0.as_ref(),
+                contributor.pubkey().as_ref(),
+            ],
+            &program_id(),
+        );
+
+        // 100 in against a 1k / 1k pool gives 90.909090 out
+        let amount_in: u64 = 100_000_000;
+        let expected_out: u64 = 90_909_090;
+
+        let donate_ix = |min_out: u64| {
+            let contribute_data = ContributeData {
+                amount: amount_in.to_le_bytes(),
+                min_out: min_out.to_le_bytes(),
+                swap: [1],
+                swap_accounts: [4],
+            };
+
+            Instruction {
+                program_id: program_id(),
+                accounts: vec![
+                    AccountMeta::new(contributor.pubkey(), true),
+                    AccountMeta::new(*mint, false),
+                    AccountMeta::new(fundraiser.0, false),
+                    AccountMeta::new(contributor_pda.0, false),
+                    AccountMeta::new(contributor_ata, false),
+                    AccountMeta::new(*vault, false),
+                    AccountMeta::new(*system_program, false),
+                    AccountMeta::new(*token_program, false),
+                    AccountMeta::new(*ata_program, false),
+                    AccountMeta::new(rent::ID, false),
+                    AccountMeta::new_readonly(*config, false),
+                    AccountMeta::new_readonly(mock_swap_program, false),
+                    AccountMeta::new(source_ata, false),
+                    AccountMeta::new_readonly(pool_authority, false),
+                    AccountMeta::new(pool_source, false),
+                    AccountMeta::new(pool_destination, false),
+                    AccountMeta::new_readonly(*token_program, false),
+                ],
+                data: [
+                    (FundraiserInstruction::Deposit as u8)
+                        .to_le_bytes()
+                        .to_vec(),
+                    contribute_data.to_bytes().to_vec(),
+                ]
+                .concat(),
+            }
+        };
+
+        // asking for more than the pool gives fails on slippage, nothing is credited
+        let message = Message::new(&[donate_ix(95_000_000)], Some(&contributor.pubkey()));
+        let transaction = Transaction::new(&[&contributor], message, svm.latest_blockhash());
+        assert!(svm.send_transaction(transaction).is_err());
+        assert!(svm.get_account(&contributor_pda.0).is_none());
+        assert_eq!(token_balance(svm, &source_ata), 1_000_000_000);
+
+        let message = Message::new(&[donate_ix(90_000_000)], Some(&contributor.pubkey()));
+        let recent_blockhash = svm.latest_blockhash();
+
+        let transaction = Transaction::new(&[&contributor], message, recent_blockhash);
+
+        let tx = svm.send_transaction(transaction).unwrap();
+
+        println!("\nDonate with swap transaction sucessfull");
+        println!("CUs Consumed: {}", tx.compute_units_consumed);
+
+        // only the post swap amount is credited
+        let contributor_account = svm.get_account(&contributor_pda.0).unwrap();
+        let contributor_state = bytemuck::from_bytes::<Contributor>(&contributor_account.data);
+        assert_eq!(u64::from_le_bytes(contributor_state.amount), expected_out);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,297 @@
+#[cfg(test)]
+pub mod transfer_contribution {
+    use core::fmt::Error;
+
+    use crate::instructions::{ContributeData, FundraiserInstruction};
+    use crate::state::Contributor;
+    use crate::tests::tests::{program_id, ReusableState};
+    use litesvm::LiteSVM;
+    use litesvm_token::{CreateAssociatedTokenAccount, MintTo};
+    use solana_instruction::{AccountMeta, Instruction};
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_pubkey::Pubkey;
+    use solana_sdk_ids::sysvar::rent;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    // contributor moves half of their position to a fresh wallet
+    pub fn transfer_contribution_function(
+        svm: &mut LiteSVM,
+        state: &ReusableState,
+        contributor: &Keypair,
+    ) -> Result<(), Error> {
+        let ReusableState {
+            fundraiser,
+            system_program,
+            config,
+            ..
+        } = state;
+
+        let recipient = Keypair::new();
+
+        let contributor_pda = |wallet: &Pubkey| {
+            Pubkey::find_program_address(
+                &[
+                    b"contributor".as_ref(),
+                    fundraiser.0.as_ref(),
+                    wallet.as_ref(),
+                ],
+                &program_id(),
+            )
+            .0
+        };
+
+        let contributor_state_account = contributor_pda(&contributor.pubkey());
+        let recipient_state_account = contributor_pda(&recipient.pubkey());
+
+        let total = {
+            let contributor_account = svm.get_account(&contributor_state_account).unwrap();
+            u64::from_le_bytes(
+                bytemuck::from_bytes::<Contributor>(&contributor_account.data).amount,
+            )
+        };
+
+        let transfer_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(contributor.pubkey(), true),
+                AccountMeta::new_readonly(recipient.pubkey(), false),
+                AccountMeta::new_readonly(fundraiser.0, false),
+                AccountMeta::new(contributor_state_account, false),
+                AccountMeta::new(recipient_state_account, false),
+                AccountMeta::new_readonly(*system_program, false),
+                AccountMeta::new_readonly(*config, false),
+            ],
+            data: [
+                vec![FundraiserInstruction::TransferContribution as u8],
+                (total / 2).to_le_bytes().to_vec(),
+            ]
+            .concat(),
+        };
+
+        let message = Message::new(&[transfer_ix], Some(&contributor.pubkey()));
+        let recent_blockhash = svm.latest_blockhash();
+
+        let transaction = Transaction::new(&[contributor], message, recent_blockhash);
+
+        let tx = svm.send_transaction(transaction).unwrap();
+
+        println!("\nTransfer contribution transaction sucessfull");
+        println!("CUs Consumed: {}", tx.compute_units_consumed);
+
+        let contributor_account = svm.get_account(&contributor_state_account).unwrap();
+        let contributor_state = bytemuck::from_bytes::<Contributor>(&contributor_account.data);
+        assert_eq!(
+            u64::from_le_bytes(contributor_state.amount),
+            total - total / 2
+        );
+
+        let recipient_account = svm.get_account(&recipient_state_account).unwrap();
+        let recipient_state = bytemuck::from_bytes::<Contributor>(&recipient_account.data);
+        assert_eq!(u64::from_le_bytes(recipient_state.amount), total / 2);
+        assert_eq!(u64::from_le_bytes(recipient_state.amounts[0]), total / 2);
+
+        Ok(())
+    }
+
+    // a position spread over two mints is partly transferred, per mint amounts move pro-rata
+    // rounded down so the dust stays with the sender
+    pub fn partial_transfer_contribution_function(
+        svm: &mut LiteSVM,
+        state: &ReusableState,
+        contributor: &Keypair,
+        second_mint: &Pubkey,
+        second_vault: &Pubkey,
+    ) -> Result<(), Error> {
+        let ReusableState {
+            maker,
+            fundraiser,
+            system_program,
+            token_program,
+            ata_program,
+            config,
+            ..
+        } = state;
+
+        let contributor_pda = |wallet: &Pubkey| {
+            Pubkey::find_program_address(
+                &[
+                    b"contributor".as_ref(),
+                    fundraiser.0.as_ref(),
+                    wallet.as_ref(),
+                ],
+                &program_id(),
+            )
+            .0
+        };
+
+        let contributor_state_account = contributor_pda(&contributor.pubkey());
+
+        // 33 usdt on top of the 100 usdc already deposited
+        let contributor_ata = CreateAssociatedTokenAccount::new(svm, contributor, second_mint)
+            .owner(&contributor.pubkey())
+            .send()
+            .unwrap();
+
+        MintTo::new(svm, maker, second_mint, &contributor_ata, 1_000_000_000)
+            .send()
+            .unwrap();
+
+        let contribute_data = ContributeData {
+            amount: 33_000_000u64.to_le_bytes(),
+            min_out: 0u64.to_le_bytes(),
+            swap: [0],
+            swap_accounts: [0],
+        };
+
+        let donate_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(contributor.pubkey(), true),
+                AccountMeta::new(*second_mint, false),
+                AccountMeta::new(fundraiser.0, false),
+                AccountMeta::new(contributor_state_account, false),
+                AccountMeta::new(contributor_ata, false),
+                AccountMeta::new(*second_vault, false),
+                AccountMeta::new(*system_program, false),
+                AccountMeta::new(*token_program, false),
+                AccountMeta::new(*ata_program, false),
+                AccountMeta::new(rent::ID, false),
+                AccountMeta::new_readonly(*config, false),
+            ],
+            data: [
+                (FundraiserInstruction::Deposit as u8)
+                    .to_le_bytes()
+                    .to_vec(),
+                contribute_data.to_bytes().to_vec(),
+            ]
+            .concat(),
+        };
+
+        let message = Message::new(&[donate_ix], Some(&contributor.pubkey()));
+        let recent_blockhash = svm.latest_blockhash();
+
+        let transaction = Transaction::new(&[contributor], message, recent_blockhash);
+
+        svm.send_transaction(transaction).unwrap();
+
+        let recipient = Keypair::new();
+        let recipient_state_account = contributor_pda(&recipient.pubkey());
+
+        let transfer_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(contributor.pubkey(), true),
+                AccountMeta::new_readonly(recipient.pubkey(), false),
+                AccountMeta::new_readonly(fundraiser.0, false),
+                AccountMeta::new(contributor_state_account, false),
+                AccountMeta::new(recipient_state_account, false),
+                AccountMeta::new_readonly(*system_program, false),
+                AccountMeta::new_readonly(*config, false),
+            ],
+            data: [
+                vec![FundraiserInstruction::TransferContribution as u8],
+                100_000_000u64.to_le_bytes().to_vec(),
+            ]
+            .concat(),
+        };
+
+        let message = Message::new(&[transfer_ix], Some(&contributor.pubkey()));
+        let recent_blockhash = svm.latest_blockhash();
+
+        let transaction = Transaction::new(&[contributor], message, recent_blockhash);
+
+        let tx = svm.send_transaction(transaction).unwrap();
+
+        println!("\nPartial transfer contribution transaction sucessfull");
+        println!("CUs Consumed: {}", tx.compute_units_consumed);
+
+        let contributor_account = svm.get_account(&contributor_state_account).unwrap();
+        let contributor_state = bytemuck::from_bytes::<Contributor>(&contributor_account.data);
+
+        let recipient_account = svm.get_account(&recipient_state_account).unwrap();
+        let recipient_state = bytemuck::from_bytes::<Contributor>(&recipient_account.data);
+
+        // 100 of 133 moves: 100 * 100 / 133 usdc and 33 * 100 / 133 usdt, both rounded down
+        assert_eq!(u64::from_le_bytes(recipient_state.amount), 100_000_000);
+        assert_eq!(u64::from_le_bytes(recipient_state.amounts[0]), 75_187_969);
+        assert_eq!(u64::from_le_bytes(recipient_state.amounts[1]), 24_812_030);
+
+        assert_eq!(u64::from_le_bytes(contributor_state.amount), 33_000_000);
+        assert_eq!(u64::from_le_bytes(contributor_state.amounts[0]), 24_812_031);
+        assert_eq!(u64::from_le_bytes(contributor_state.amounts[1]), 8_187_970);
+
+        // nothing is created or lost per mint
+        assert_eq!(
+            u64::from_le_bytes(contributor_state.amounts[0])
+                + u64::from_le_bytes(recipient_state.amounts[0]),
+            100_000_000
+        );
+        assert_eq!(
+            u64::from_le_bytes(contributor_state.amounts[1])
+                + u64::from_le_bytes(recipient_state.amounts[1]),
+            33_000_000
+        );
+
+        Ok(())
+    }
+
+    // a gated raise keeps positions with the wallets that passed the gate
+    pub fn transfer_gated_contribution_function(
+        svm: &mut LiteSVM,
+        state: &ReusableState,
+        contributor: &Keypair,
+    ) -> Result<(), Error> {
+        let ReusableState {
+            fundraiser,
+            system_program,
+            config,
+            ..
+        } = state;
+
+        let recipient = Keypair::new();
+
+        let contributor_pda = |wallet: &Pubkey| {
+            Pubkey::find_program_address(
+                &[
+                    b"contributor".as_ref(),
+                    fundraiser.0.as_ref(),
+                    wallet.as_ref(),
+                ],
+                &program_id(),
+            )
+            .0
+        };
+
+        let transfer_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(contributor.pubkey(), true),
+                AccountMeta::new_readonly(recipient.pubkey(), false),
+                AccountMeta::new_readonly(fundraiser.0, false),
+                AccountMeta::new(contributor_pda(&contributor.pubkey()), false),
+                AccountMeta::new(contributor_pda(&recipient.pubkey()), false),
+                AccountMeta::new_readonly(*system_program, false),
+                AccountMeta::new_readonly(*config, false),
+            ],
+            data: [
+                vec![FundraiserInstruction::TransferContribution as u8],
+                50_000_000u64.to_le_bytes().to_vec(),
+            ]
+            .concat(),
+        };
+
+        let message = Message::new(&[transfer_ix], Some(&contributor.pubkey()));
+        let recent_blockhash = svm.latest_blockhash();
+
+        let transaction = Transaction::new(&[contributor], message, recent_blockhash);
+
+        assert!(svm.send_transaction(transaction).is_err());
+
+        let recipient_account = svm.get_account(&contributor_pda(&recipient.pubkey()));
+        assert!(recipient_account.map_or(true, |account| account.data.is_empty()));
+
+        Ok(())
+    }
+}
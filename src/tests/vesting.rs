@@ -0,0 +1,127 @@
+#[cfg(test)]
+pub mod vesting {
+    use core::fmt::Error;
+
+    use bytemuck::Zeroable;
+
+    use crate::instructions::{FundraiserInstruction, InitData};
+    use crate::state::{Fundraiser, GOAL_MODE_TOKEN};
+    use crate::tests::tests::{current_unix_timestamp, program_id, token_balance, ReusableState};
+    use litesvm::LiteSVM;
+    use solana_instruction::{AccountMeta, Instruction};
+    use solana_message::Message;
+    use solana_sdk_ids::sysvar::rent;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    const DAY: i64 = 24 * 60 * 60;
+
+    // 100 usdc goal vesting linearly from day 4 to day 6
+    pub fn create_vesting_fundraiser_function(
+        svm: &mut LiteSVM,
+        state: &ReusableState,
+    ) -> Result<(), Error> {
+        let ReusableState {
+            maker,
+            fundraiser,
+            mint,
+            vault,
+            system_program,
+            token_program,
+            ata_program,
+            config,
+            ..
+        } = state;
+
+        let now = current_unix_timestamp(svm);
+
+        let initialize_data = InitData {
+            amount_to_raise: 100_000_000u64.to_le_bytes(),
+            duration: 3u8.to_le_bytes(),
+            goal_mode: [GOAL_MODE_TOKEN],
+            vesting_cliff: (now + 4 * DAY).to_le_bytes(),
+            vesting_end: (now + 6 * DAY).to_le_bytes(),
+            ..Zeroable::zeroed()
+        };
+
+        let make_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new(*mint, false),
+                AccountMeta::new(fundraiser.0, false),
+                AccountMeta::new(*vault, false),
+                AccountMeta::new(*system_program, false),
+                AccountMeta::new(*token_program, false),
+                AccountMeta::new(*ata_program, false),
+                AccountMeta::new(rent::ID, false),
+                AccountMeta::new_readonly(*config, false),
+            ],
+            data: [
+                (FundraiserInstruction::Initialize as u8)
+                    .to_le_bytes()
+                    .to_vec(),
+                initialize_data.to_bytes().to_vec(),
+            ]
+            .concat(),
+        };
+
+        let message = Message::new(&[make_ix], Some(&maker.pubkey()));
+        let recent_blockhash = svm.latest_blockhash();
+
+        let transaction = Transaction::new(&[&maker], message, recent_blockhash);
+
+        let tx = svm.send_transaction(transaction).unwrap();
+
+        println!("\nVesting initialize transaction sucessfull");
+        println!("CUs Consumed: {}", tx.compute_units_consumed);
+
+        Ok(())
+    }
+
+    // halfway between cliff and end, half of the raise is withdrawable
+    pub fn withdraw_vested_function(svm: &mut LiteSVM, state: &ReusableState) -> Result<(), Error> {
+        let ReusableState {
+            maker,
+            maker_ata,
+            fundraiser,
+            vault,
+            token_program,
+            ..
+        } = state;
+
+        let withdraw_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new(fundraiser.0, false),
+                AccountMeta::new_readonly(*token_program, false),
+                AccountMeta::new(*vault, false),
+                AccountMeta::new(*maker_ata, false),
+            ],
+            data: vec![FundraiserInstruction::Withdraw as u8],
+        };
+
+        let message = Message::new(&[withdraw_ix], Some(&maker.pubkey()));
+        let recent_blockhash = svm.latest_blockhash();
+
+        let transaction = Transaction::new(&[&maker], message, recent_blockhash);
+
+        let tx = svm.send_transaction(transaction).unwrap();
+
+        println!("\nWithdraw transaction sucessfull");
+        println!("CUs Consumed: {}", tx.compute_units_consumed);
+
+        assert_eq!(token_balance(svm, maker_ata), 50_000_000);
+        assert_eq!(token_balance(svm, vault), 50_000_000);
+
+        let fundraiser_account = svm.get_account(&fundraiser.0).unwrap();
+        let accepted_mints = Fundraiser::accepted_mints(&fundraiser_account.data);
+        assert_eq!(
+            u64::from_le_bytes(accepted_mints[0].withdrawn_amount),
+            50_000_000
+        );
+
+        Ok(())
+    }
+}
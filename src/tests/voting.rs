@@ -0,0 +1,217 @@
+#[cfg(test)]
+pub mod voting {
+    use core::fmt::Error;
+
+    use bytemuck::Zeroable;
+
+    use crate::instructions::{FundraiserInstruction, InitData};
+    use crate::state::{Contributor, Fundraiser, GOAL_MODE_TOKEN, MILESTONE_RELEASED};
+    use crate::tests::tests::{program_id, token_balance, warp_forward, ReusableState};
+    use litesvm::LiteSVM;
+    use solana_instruction::{AccountMeta, Instruction};
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_pubkey::Pubkey;
+    use solana_sdk_ids::sysvar::rent;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    pub const VOTE_DURATION: i64 = 24 * 60 * 60;
+
+    // 100 usdc goal in two 50% milestones approved by contributor vote
+    pub fn create_vote_fundraiser_function(
+        svm: &mut LiteSVM,
+        state: &ReusableState,
+    ) -> Result<(), Error> {
+        let ReusableState {
+            maker,
+            fundraiser,
+            mint,
+            vault,
+            system_program,
+            token_program,
+            ata_program,
+            config,
+            ..
+        } = state;
+
+        let mut milestones_bps = [[0u8; 2]; 8];
+        milestones_bps[0] = 5_000u16.to_le_bytes();
+        milestones_bps[1] = 5_000u16.to_le_bytes();
+
+        let initialize_data = InitData {
+            amount_to_raise: 100_000_000u64.to_le_bytes(),
+            duration: 3u8.to_le_bytes(),
+            goal_mode: [GOAL_MODE_TOKEN],
+            quorum_bps: 5_000u16.to_le_bytes(),
+            threshold_bps: 6_000u16.to_le_bytes(),
+            vote_duration: (VOTE_DURATION as u64).to_le_bytes(),
+            milestones_count: [2],
+            milestones_bps,
+            ..Zeroable::zeroed()
+        };
+
+        let make_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new(*mint, false),
+                AccountMeta::new(fundraiser.0, false),
+                AccountMeta::new(*vault, false),
+                AccountMeta::new(*system_program, false),
+                AccountMeta::new(*token_program, false),
+                AccountMeta::new(*ata_program, false),
+                AccountMeta::new(rent::ID, false),
+                AccountMeta::new_readonly(*config, false),
+            ],
+            data: [
+                (FundraiserInstruction::Initialize as u8)
+                    .to_le_bytes()
+                    .to_vec(),
+                initialize_data.to_bytes().to_vec(),
+            ]
+            .concat(),
+        };
+
+        let message = Message::new(&[make_ix], Some(&maker.pubkey()));
+        let recent_blockhash = svm.latest_blockhash();
+
+        let transaction = Transaction::new(&[&maker], message, recent_blockhash);
+
+        let tx = svm.send_transaction(transaction).unwrap();
+
+        println!("\nVote initialize transaction sucessfull");
+        println!("CUs Consumed: {}", tx.compute_units_consumed);
+
+        Ok(())
+    }
+
+    // maker submits the first milestone and the contributor votes to approve it
+    pub fn submit_and_vote_function(
+        svm: &mut LiteSVM,
+        state: &ReusableState,
+        contributor: &Keypair,
+    ) -> Result<(), Error> {
+        let ReusableState {
+            maker,
+            fundraiser,
+            system_program,
+            ..
+        } = state;
+
+        let contributor_pda = Pubkey::find_program_address(
+            &[
+                b"contributor".as_ref(),
+                fundraiser.0.as_ref(),
+                contributor.pubkey().as_ref(),
+            ],
+            &program_id(),
+        );
+        let vote = Pubkey::find_program_address(
+            &[
+                b"vote".as_ref(),
+                fundraiser.0.as_ref(),
+                &[0],
+                contributor.pubkey().as_ref(),
+            ],
+            &program_id(),
+        );
+
+        let submit_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new(fundraiser.0, false),
+            ],
+            data: vec![FundraiserInstruction::SubmitMilestone as u8, 0],
+        };
+
+        let vote_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(contributor.pubkey(), true),
+                AccountMeta::new(fundraiser.0, false),
+                AccountMeta::new(contributor_pda.0, false),
+                AccountMeta::new(vote.0, false),
+                AccountMeta::new(*system_program, false),
+            ],
+            data: vec![FundraiserInstruction::CastVote as u8, 0, 1],
+        };
+
+        let message = Message::new(&[submit_ix, vote_ix], Some(&maker.pubkey()));
+        let recent_blockhash = svm.latest_blockhash();
+
+        let transaction = Transaction::new(&[&maker, contributor], message, recent_blockhash);
+
+        let tx = svm.send_transaction(transaction).unwrap();
+
+        println!("\nSubmit and vote transaction sucessfull");
+        println!("CUs Consumed: {}", tx.compute_units_consumed);
+
+        let fundraiser_account = svm.get_account(&fundraiser.0).unwrap();
+        let fundraiser_state =
+            bytemuck::from_bytes::<Fundraiser>(&fundraiser_account.data[..Fundraiser::LEN]);
+        assert_eq!(
+            u64::from_le_bytes(fundraiser_state.milestones[0].approve_weight),
+            100_000_000
+        );
+
+        // weight stays locked until the vote closes
+        let contributor_account = svm.get_account(&contributor_pda.0).unwrap();
+        let contributor_state = bytemuck::from_bytes::<Contributor>(&contributor_account.data);
+        assert_eq!(
+            i64::from_le_bytes(contributor_state.locked_until),
+            i64::from_le_bytes(fundraiser_state.milestones[0].submitted_at) + VOTE_DURATION
+        );
+
+        Ok(())
+    }
+
+    // anyone tallies once the vote closed, the approved tranche goes to the maker
+    pub fn tally_first_milestone_function(
+        svm: &mut LiteSVM,
+        state: &ReusableState,
+    ) -> Result<(), Error> {
+        let ReusableState {
+            maker,
+            maker_ata,
+            fundraiser,
+            vault,
+            token_program,
+            ..
+        } = state;
+
+        warp_forward(svm, VOTE_DURATION);
+
+        let tally_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(fundraiser.0, false),
+                AccountMeta::new_readonly(*token_program, false),
+                AccountMeta::new(*vault, false),
+                AccountMeta::new(*maker_ata, false),
+            ],
+            data: vec![FundraiserInstruction::TallyMilestone as u8, 0],
+        };
+
+        let message = Message::new(&[tally_ix], Some(&maker.pubkey()));
+        let recent_blockhash = svm.latest_blockhash();
+
+        let transaction = Transaction::new(&[&maker], message, recent_blockhash);
+
+        let tx = svm.send_transaction(transaction).unwrap();
+
+        println!("\nTally milestone transaction sucessfull");
+        println!("CUs Consumed: {}", tx.compute_units_consumed);
+
+        assert_eq!(token_balance(svm, maker_ata), 50_000_000);
+        assert_eq!(token_balance(svm, vault), 50_000_000);
+
+        let fundraiser_account = svm.get_account(&fundraiser.0).unwrap();
+        let fundraiser_state =
+            bytemuck::from_bytes::<Fundraiser>(&fundraiser_account.data[..Fundraiser::LEN]);
+        assert_eq!(fundraiser_state.milestones[0].status, [MILESTONE_RELEASED]);
+
+        Ok(())
+    }
+}